@@ -20,7 +20,19 @@ fn generate_impl() -> anyhow::Result<()> {
     "#
         .to_owned(),
     );
-    for name in &["CBoxedStr", "CBoxedSlice", "CBox", "COptionBox", "SliceRef"] {
+    for name in &[
+        "BoxedStr",
+        "BoxedSlice",
+        "ByteSliceRef",
+        "CBoxedStr",
+        "CBoxedSlice",
+        "CBox",
+        "CByteSliceRef",
+        "COptionBox",
+        "CStrRef",
+        "SliceRef",
+        "StrRef",
+    ] {
         config.export.exclude.push(name.to_string());
         config
             .export
@@ -37,19 +49,22 @@ fn generate_impl() -> anyhow::Result<()> {
 }
 
 #[cfg(feature = "__build_header")]
-fn concat_header() -> anyhow::Result<()> {
-    let mut cxx_dir = crate_dir()?;
-    cxx_dir.push_str("/cxx");
-
+fn header_parts(dir: &str) -> anyhow::Result<Vec<std::path::PathBuf>> {
     let mut paths = Vec::new();
-    for entry in std::fs::read_dir(cxx_dir)? {
+    for entry in std::fs::read_dir(dir)? {
         let entry = entry?;
         let path = entry.path();
         if path.is_file() && path.extension().unwrap_or_default() == "hxx" {
             paths.push(path);
         }
     }
-    paths.sort_unstable();
+    Ok(paths)
+}
+
+#[cfg(feature = "__build_header")]
+fn write_header(mut paths: Vec<std::path::PathBuf>, file_name: &str) -> anyhow::Result<()> {
+    // parts are ordered by the number prefix of the file names regardless of the directory
+    paths.sort_unstable_by(|a, b| a.file_name().cmp(&b.file_name()));
 
     let mut out_content = String::new();
     paths.iter().for_each(|path| {
@@ -59,13 +74,28 @@ fn concat_header() -> anyhow::Result<()> {
     });
 
     let mut out_path = crate_dir()?;
-    out_path.push_str("/include");
-    out_path.push_str("/rust_types.hxx");
+    out_path.push_str("/include/");
+    out_path.push_str(file_name);
     std::fs::write(out_path, out_content)?;
 
     Ok(())
 }
 
+#[cfg(feature = "__build_header")]
+fn concat_header() -> anyhow::Result<()> {
+    let mut cxx_dir = crate_dir()?;
+    cxx_dir.push_str("/cxx");
+
+    let paths = header_parts(&cxx_dir)?;
+    write_header(paths.clone(), "rust_types.hxx")?;
+
+    let mut cxx20_paths = paths;
+    cxx20_paths.extend(header_parts(&format!("{}/cxx20", cxx_dir))?);
+    write_header(cxx20_paths, "rust_types_cxx20.hxx")?;
+
+    Ok(())
+}
+
 #[cfg(feature = "__build_header")]
 fn make_header() -> anyhow::Result<()> {
     generate_impl()?;
@@ -76,6 +106,11 @@ fn make_header() -> anyhow::Result<()> {
         .file("cxx/test.cxx")
         .cpp(true)
         .compile("cxx_header_test");
+    cc::Build::new()
+        .std("c++20")
+        .file("cxx/test.cxx")
+        .cpp(true)
+        .compile("cxx20_header_test");
 
     Ok(())
 }
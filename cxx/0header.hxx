@@ -2,7 +2,9 @@
 
 #include <array>
 #include <cassert>
+#include <cstddef>
 #include <cstdint>
+#include <cstring>
 #include <string>
 #include <vector>
 #if __cpp_lib_ranges
@@ -76,6 +78,10 @@ using usize = uintptr_t;
 template <typename T, usize N>
 using Array = std::array<T, N>;
 
+/// Alias for Rust `ArrayRef<T, N>`, a pointer to exactly `N` elements.
+template <typename T, usize N>
+using ArrayRef = const Array<T, N>*;
+
 }  // namespace ffi_types
 
 #if _MSC_VER
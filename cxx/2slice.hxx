@@ -1,10 +1,11 @@
 namespace ffi_types {
 
-#define EMPTY_SLICE_BEGIN(T) reinterpret_cast<T*>(1)
+// A dangling pointer aligned for T, the same as an empty slice in Rust.
+#define EMPTY_SLICE_BEGIN(T) reinterpret_cast<T*>(alignof(T))
 
 template<typename T>
 T* _wrap_null(T* ptr) {
-    return ptr ? ptr : reinterpret_cast<T*>(1);
+    return ptr ? ptr : EMPTY_SLICE_BEGIN(T);
 }
 
 // C++ std::ranges compatibility layer until C++17.
@@ -48,6 +49,7 @@ size_t size(C& x) noexcept {
 #endif
 
 struct CharStrRef;
+struct CharStrMutRef;
 struct StrRef;
 struct CStrRef;
 struct CByteSliceRef;
@@ -450,6 +452,18 @@ struct [[nodiscard]] CBoxedSlice {
 static_assert(std::is_trivial<CBoxedSlice<int>>::value);
 static_assert(std::is_standard_layout<CBoxedSlice<int>>::value);
 
+// Compares strings by contents like `&str` in Rust. An empty string may have a dangling pointer, so it is never read.
+inline bool _char_str_equal(const char* a, usize a_size, const char* b, usize b_size) noexcept {
+    return a_size == b_size && (a_size == 0 || std::memcmp(a, b, a_size) == 0);
+}
+
+// Lexicographical comparison by bytes like `Ord for str` in Rust.
+inline bool _char_str_less(const char* a, usize a_size, const char* b, usize b_size) noexcept {
+    const usize size = a_size < b_size ? a_size : b_size;
+    const int result = size == 0 ? 0 : std::memcmp(a, b, size);
+    return result < 0 || (result == 0 && a_size < b_size);
+}
+
 /// C++ unsafe counterpart of Rust `&str`.
 ///
 /// Because `StrRef` in C++ side doesn't have any UTF-8 validation checking,
@@ -525,6 +539,17 @@ struct CharStrRef {
         return {data(), size()};
     }
 
+    // comparison by contents, also for `StrRef` and `BoxedStr`
+    bool operator==(const CharStrRef& other) const noexcept {
+        return _char_str_equal(this->_data, this->_size, other._data, other._size);
+    }
+    bool operator!=(const CharStrRef& other) const noexcept {
+        return !(*this == other);
+    }
+    bool operator<(const CharStrRef& other) const noexcept {
+        return _char_str_less(this->_data, this->_size, other._data, other._size);
+    }
+
 #if __cpp_lib_span
     /// Returns the slice as a `std::span`.
     std::span<element_type> span() const noexcept {
@@ -559,6 +584,22 @@ static_assert(sizeof(SliceRef<char>) == sizeof(CharStrRef));
 static_assert(std::is_trivial<CharStrRef>::value);
 static_assert(std::is_standard_layout<CharStrRef>::value);
 
+/// C++ counterpart of Rust `CharStrMutRef`, a writable char buffer to be modified in place by Rust side.
+///
+/// @warning Never create it over read-only memory like string literals.
+struct CharStrMutRef : public MutSliceRef<char> {
+    using MutSliceRef<char>::MutSliceRef;
+    CharStrMutRef(std::nullptr_t) noexcept : MutSliceRef<char>() {}
+
+    /// Returns a read-only view of the buffer.
+    CharStrRef as_char_str() const noexcept {
+        return CharStrRef(this->_data, this->_size);
+    }
+};
+static_assert(sizeof(CharStrMutRef) == sizeof(CharStrRef));
+static_assert(std::is_trivially_copyable<CharStrMutRef>::value);
+static_assert(std::is_standard_layout<CharStrMutRef>::value);
+
 /// C++ counterpart of Rust `&str`.
 ///
 /// Because `StrRef` in C++ side doesn't have any UTF-8 validation checking,
@@ -599,6 +640,17 @@ struct CStrRef {
         s._size = this->_size;
         return s;
     }
+
+    // comparison by contents
+    bool operator==(const CStrRef& other) const noexcept {
+        return _char_str_equal(this->_data, this->_size, other._data, other._size);
+    }
+    bool operator!=(const CStrRef& other) const noexcept {
+        return !(*this == other);
+    }
+    bool operator<(const CStrRef& other) const noexcept {
+        return _char_str_less(this->_data, this->_size, other._data, other._size);
+    }
 };
 static_assert(std::is_trivial<CStrRef>::value);
 static_assert(std::is_standard_layout<CStrRef>::value);
@@ -699,6 +751,17 @@ struct [[nodiscard]] CBoxedStr {
         this->_size = 0;
         return range;
     }
+
+    // comparison by contents
+    bool operator==(const CBoxedStr& other) const noexcept {
+        return _char_str_equal(this->_data, this->_size, other._data, other._size);
+    }
+    bool operator!=(const CBoxedStr& other) const noexcept {
+        return !(*this == other);
+    }
+    bool operator<(const CBoxedStr& other) const noexcept {
+        return _char_str_less(this->_data, this->_size, other._data, other._size);
+    }
 };
 static_assert(std::is_trivial<CBoxedStr>::value);
 static_assert(std::is_standard_layout<CBoxedStr>::value);
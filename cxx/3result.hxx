@@ -0,0 +1,55 @@
+namespace ffi_types {
+
+/// C++ counterpart of Rust `FfiResultTag`.
+enum class FfiResultTag : uint8_t {
+    Ok = 0,
+    Err = 1,
+};
+
+/// C++ wrapper for Rust `FfiResult<T, E>` with C ABI compatible layout.
+///
+/// The active member of the union is chosen by `tag`. Both values are Rust boxes.
+///
+/// @warning This type does *NOT* implement a safe destructor.
+///          To avoid leak, take the value by `ok()` or `err()`, or pass it back to Rust side.
+template <typename T, typename E>
+struct [[nodiscard]] FfiResult {
+    FfiResultTag tag;
+    union {
+        T* _ok;
+        E* _err;
+    };
+
+    // observers
+    bool is_ok() const noexcept {
+        return this->tag == FfiResultTag::Ok;
+    }
+    bool is_err() const noexcept {
+        return this->tag == FfiResultTag::Err;
+    }
+
+    /// Takes the `Ok` value as an owned box.
+    /// The result must be `Ok` and must not be passed to Rust side afterward.
+    OptionBox<T> ok() noexcept {
+        assert(this->is_ok());
+        auto box = OptionBox<T>(nullptr);
+        box._ptr = this->_ok;
+        this->_ok = nullptr;
+        return box;
+    }
+
+    /// Takes the `Err` value as an owned box.
+    /// The result must be `Err` and must not be passed to Rust side afterward.
+    OptionBox<E> err() noexcept {
+        assert(this->is_err());
+        auto box = OptionBox<E>(nullptr);
+        box._ptr = this->_err;
+        this->_err = nullptr;
+        return box;
+    }
+};
+static_assert(sizeof(FfiResult<int, int>) == sizeof(usize) * 2);
+static_assert(std::is_trivial<FfiResult<int, int>>::value);
+static_assert(std::is_standard_layout<FfiResult<int, int>>::value);
+
+}  // namespace ffi_types
@@ -0,0 +1,50 @@
+namespace ffi_types {
+
+/// C++ counterpart of Rust `FfiOption<T>` for small trivially copyable values.
+///
+/// The layout is `{ uint8_t has_value; T value; }` with the usual C padding after `has_value` up to `alignof(T)`.
+/// `_value` is uninitialized unless `_has_value` is set.
+template <typename T>
+struct FfiOption {
+    static_assert(std::is_trivially_copyable<T>::value, "FfiOption requires a trivially copyable type");
+
+    uint8_t _has_value;
+    union {
+        T _value;
+    };
+
+    static FfiOption some(T value) noexcept {
+        FfiOption option;
+        option._has_value = 1;
+        option._value = value;
+        return option;
+    }
+    static FfiOption none() noexcept {
+        FfiOption option;
+        option._has_value = 0;
+        return option;
+    }
+
+    // observers
+    bool has_value() const noexcept {
+        return this->_has_value != 0;
+    }
+    explicit operator bool() const noexcept {
+        return this->has_value();
+    }
+    /// The option must have a value.
+    const T& value() const noexcept {
+        assert(this->has_value());
+        return this->_value;
+    }
+    T value_or(T default_value) const noexcept {
+        return this->has_value() ? this->_value : default_value;
+    }
+};
+static_assert(sizeof(FfiOption<uint32_t>) == 8);
+static_assert(offsetof(FfiOption<uint32_t>, _value) == 4);
+static_assert(sizeof(FfiOption<bool>) == 2);
+static_assert(std::is_trivial<FfiOption<uint32_t>>::value);
+static_assert(std::is_standard_layout<FfiOption<uint32_t>>::value);
+
+}  // namespace ffi_types
@@ -6,12 +6,34 @@
 
 namespace ffi_types {
 
+/// Version of the `#[repr(C)]` layouts of the types in this crate.
+///
+/// This is bumped whenever any layout changes. The C++ header has the same constant generated from this.
+/// Compare it with `_rust_ffi_abi_version()` at startup to catch a header and a library from different versions,
+/// e.g. `assert(ffi_types::abi_version_matches())` in C++.
+constexpr static const uint32_t FFI_TYPES_ABI_VERSION = 2;
+
 extern "C" {
 
 void _rust_ffi_boxed_str_drop(ffi_types::CBoxedStr _string);
 
 void _rust_ffi_boxed_bytes_drop(ffi_types::CBoxedSlice<uint8_t> _slice);
 
+void _rust_ffi_option_box_str_drop(ffi_types::COptionBox<ffi_types::BoxedStr> boxed);
+
+void _rust_ffi_option_box_bytes_drop(ffi_types::COptionBox<ffi_types::BoxedSlice<uint8_t>> boxed);
+
+/// Returns the length of `slice` without relying on the struct layout of the header.
+///
+/// This is a fallback for a header out of sync with the library and a symbol to link-check against.
+uintptr_t _rust_ffi_slice_len(ffi_types::CByteSliceRef slice);
+
+/// Returns the length of `s` in bytes. See `_rust_ffi_slice_len`.
+uintptr_t _rust_ffi_str_len(ffi_types::CStrRef s);
+
+/// Returns `FFI_TYPES_ABI_VERSION` of the linked library.
+uint32_t _rust_ffi_abi_version();
+
 } // extern "C"
 
 } // namespace ffi_types
@@ -1,5 +1,11 @@
 namespace ffi_types {
 
+/// Returns whether the linked Rust library has the same layouts as this header.
+/// Check it at startup to detect a header and a library from different versions.
+inline bool abi_version_matches() noexcept {
+    return ffi_types::_rust_ffi_abi_version() == ffi_types::FFI_TYPES_ABI_VERSION;
+}
+
 inline void BoxedStr::_drop() noexcept {
     ffi_types::_rust_ffi_boxed_str_drop(CBoxedStr::from(std::move(*this)));
 }
@@ -9,4 +15,14 @@ inline void BoxedSlice<uint8_t>::_drop() noexcept {
     ffi_types::_rust_ffi_boxed_bytes_drop(CBoxedSlice<uint8_t>::from(std::move(*this)));
 }
 
+template <>
+inline void OptionBox<BoxedStr>::_drop() noexcept {
+    ffi_types::_rust_ffi_option_box_str_drop(COptionBox<BoxedStr>::from(std::move(*this)));
+}
+
+template <>
+inline void OptionBox<BoxedSlice<uint8_t>>::_drop() noexcept {
+    ffi_types::_rust_ffi_option_box_bytes_drop(COptionBox<BoxedSlice<uint8_t>>::from(std::move(*this)));
+}
+
 }  // namespace ffi_types
\ No newline at end of file
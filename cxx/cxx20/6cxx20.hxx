@@ -0,0 +1,39 @@
+// C++20 extensions, only included in rust_types_cxx20.hxx.
+#if !((defined(_MSVC_LANG) ? _MSVC_LANG : __cplusplus) >= 202002L)
+#error "rust_types_cxx20.hxx requires C++20. Use rust_types.hxx for C++17."
+#endif
+
+namespace ffi_types {
+
+/// Creates a `SliceRef` over a `std::span` of const elements.
+template <typename T, std::size_t E>
+[[nodiscard]] SliceRef<T> to_slice_ref(std::span<const T, E> span) noexcept {
+    return SliceRef<T>(span.data(), span.size());
+}
+
+/// Creates a `MutSliceRef` over a `std::span` of mutable elements.
+template <typename T, std::size_t E>
+    requires(!std::is_const_v<T>)
+[[nodiscard]] MutSliceRef<T> to_mut_slice_ref(std::span<T, E> span) noexcept {
+    return MutSliceRef<T>(span.data(), span.size());
+}
+
+}  // namespace ffi_types
+
+// Slice references don't own the elements, so iterators outlive the reference itself like `std::span`.
+template <typename T>
+inline constexpr bool std::ranges::enable_borrowed_range<ffi_types::MutSliceRef<T>> = true;
+template <typename T>
+inline constexpr bool std::ranges::enable_borrowed_range<ffi_types::SliceRef<T>> = true;
+template <>
+inline constexpr bool std::ranges::enable_borrowed_range<ffi_types::StrRef> = true;
+template <typename T>
+inline constexpr bool std::ranges::enable_view<ffi_types::MutSliceRef<T>> = true;
+template <typename T>
+inline constexpr bool std::ranges::enable_view<ffi_types::SliceRef<T>> = true;
+
+static_assert(std::ranges::contiguous_range<ffi_types::SliceRef<int>>);
+static_assert(std::ranges::borrowed_range<ffi_types::SliceRef<int>>);
+static_assert(std::ranges::view<ffi_types::SliceRef<int>>);
+static_assert(std::ranges::view<ffi_types::MutSliceRef<int>>);
+static_assert(std::ranges::borrowed_range<ffi_types::StrRef>);
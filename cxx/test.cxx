@@ -1,11 +1,23 @@
+#include <algorithm>
+
 #include "0header.hxx"
 #include "1boxed.hxx"
 #include "2slice.hxx"
+#include "3result.hxx"
+#include "4option.hxx"
+#if __cplusplus >= 202002L
+#include "cxx20/6cxx20.hxx"
+#endif
 #include "7rust_impl.hxx"
 #include "8cxx_impl.hxx"
 #include "9footer.hxx"
 
 void ffi_types::_rust_ffi_boxed_str_drop(ffi_types::CBoxedStr) {}
+void ffi_types::_rust_ffi_option_box_str_drop(ffi_types::COptionBox<ffi_types::BoxedStr>) {}
+void ffi_types::_rust_ffi_option_box_bytes_drop(ffi_types::COptionBox<ffi_types::BoxedSlice<uint8_t>>) {}
+uint32_t ffi_types::_rust_ffi_abi_version() {
+    return ffi_types::FFI_TYPES_ABI_VERSION;
+}
 
 template struct ffi_types::CBox<char>;
 template <>
@@ -42,6 +54,18 @@ ffi_types::CBoxedStr signature_c_boxed_str(ffi_types::CBoxedStr c) {
 ffi_types::CharStrRef signature_char_str_ref(ffi_types::CharStrRef c) {
     return c;
 }
+ffi_types::CharStrMutRef signature_char_str_mut_ref(ffi_types::CharStrMutRef c) {
+    return c;
+}
+ffi_types::FfiResult<char, char> signature_ffi_result(ffi_types::FfiResult<char, char> c) {
+    return c;
+}
+ffi_types::ArrayRef<uint8_t, 4> signature_array_ref(ffi_types::ArrayRef<uint8_t, 4> c) {
+    return c;
+}
+ffi_types::FfiOption<uint32_t> signature_ffi_option(ffi_types::FfiOption<uint32_t> c) {
+    return c;
+}
 }
 
 template <typename C>
@@ -136,11 +160,94 @@ void test_move_boxed_str() {
     assert(moved_boxed.size() == 5);
 }
 
+void test_empty_slice_alignment() {
+    auto slice = ffi_types::SliceRef<uint64_t>(nullptr, 0);
+    assert(reinterpret_cast<uintptr_t>(slice.data()) == alignof(uint64_t));
+
+    auto boxed = ffi_types::BoxedSlice<char>(nullptr);
+    assert(reinterpret_cast<uintptr_t>(boxed.data()) == alignof(char));
+}
+
+void test_char_str_mut() {
+    char buffer[] = "hello";
+    auto chars = ffi_types::CharStrMutRef(buffer, 5);
+    chars[0] = 'j';
+    assert(chars.as_char_str().view() == "jello");
+
+    auto empty = ffi_types::CharStrMutRef(nullptr);
+    assert(empty.empty());
+}
+
+void test_str_compare() {
+    const char buffer[] = "hello hello";
+    auto a = ffi_types::CharStrRef(buffer, 5);
+    auto b = ffi_types::CharStrRef(buffer + 6, 5);
+    assert(a.data() != b.data());
+    assert(a == b);
+    assert(!(a != b));
+    assert(a != ffi_types::CharStrRef("help"));
+    assert(a < ffi_types::CharStrRef("help"));
+    assert(ffi_types::CharStrRef("hell") < a);
+    assert(!(a < b));
+
+    auto empty = ffi_types::StrRef(nullptr);
+    assert(empty == ffi_types::CharStrRef((char*)1, 0));
+    assert(empty < a.as_str_unchecked());
+    assert(a.as_str_unchecked() == b.as_str_unchecked());
+
+    auto boxed = ffi_types::BoxedStr(nullptr);
+    assert(boxed == empty);
+
+    auto c_a = ffi_types::CStrRef(a.as_str_unchecked());
+    auto c_b = ffi_types::CStrRef(b.as_str_unchecked());
+    assert(c_a == c_b);
+    assert(!(c_a < c_b));
+    assert(ffi_types::CStrRef(empty) < c_a);
+    assert(ffi_types::CStrRef(empty) != c_a);
+}
+
+void test_ffi_option() {
+    auto some = ffi_types::FfiOption<uint32_t>::some(42);
+    assert(some);
+    assert(some.value() == 42);
+
+    auto none = ffi_types::FfiOption<bool>::none();
+    assert(!none.has_value());
+    assert(none.value_or(true));
+}
+
+#if __cplusplus >= 202002L
+void test_cxx20_span() {
+    std::vector<int> ints{1, 2, 3};
+    auto slice = ffi_types::to_slice_ref(std::span<const int>(ints));
+    assert(slice.size() == 3);
+    assert(*std::ranges::find(slice, 2) == 2);
+
+    int sum = 0;
+    for (int i : slice | std::views::take(2)) {
+        sum += i;
+    }
+    assert(sum == 3);
+
+    auto mut_slice = ffi_types::to_mut_slice_ref(std::span<int>(ints));
+    std::ranges::fill(mut_slice, 0);
+    assert(ints[2] == 0);
+}
+#endif
+
 int main() {
     test_char_str();
     test_null_str();
     test_move_boxed_slice();
     test_move_boxed_str();
+    assert(ffi_types::abi_version_matches());
+    test_empty_slice_alignment();
+    test_char_str_mut();
+    test_str_compare();
+    test_ffi_option();
+#if __cplusplus >= 202002L
+    test_cxx20_span();
+#endif
     test_iterator_begin<ffi_types::CharStrRef>();
     test_iterator_begin<ffi_types::SliceRef<char>>();
     test_iterator_begin<ffi_types::SliceRef<const char>>();
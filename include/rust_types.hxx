@@ -2,7 +2,9 @@
 
 #include <array>
 #include <cassert>
+#include <cstddef>
 #include <cstdint>
+#include <cstring>
 #include <string>
 #include <vector>
 #if __cpp_lib_ranges
@@ -76,6 +78,10 @@ using usize = uintptr_t;
 template <typename T, usize N>
 using Array = std::array<T, N>;
 
+/// Alias for Rust `ArrayRef<T, N>`, a pointer to exactly `N` elements.
+template <typename T, usize N>
+using ArrayRef = const Array<T, N>*;
+
 }  // namespace ffi_types
 
 #if _MSC_VER
@@ -259,11 +265,12 @@ inline void OptionBox<void>::_drop() noexcept {
 }  // namespace ffi_types
 namespace ffi_types {
 
-#define EMPTY_SLICE_BEGIN(T) reinterpret_cast<T*>(1)
+// A dangling pointer aligned for T, the same as an empty slice in Rust.
+#define EMPTY_SLICE_BEGIN(T) reinterpret_cast<T*>(alignof(T))
 
 template<typename T>
 T* _wrap_null(T* ptr) {
-    return ptr ? ptr : reinterpret_cast<T*>(1);
+    return ptr ? ptr : EMPTY_SLICE_BEGIN(T);
 }
 
 // C++ std::ranges compatibility layer until C++17.
@@ -307,6 +314,7 @@ size_t size(C& x) noexcept {
 #endif
 
 struct CharStrRef;
+struct CharStrMutRef;
 struct StrRef;
 struct CStrRef;
 struct CByteSliceRef;
@@ -709,6 +717,18 @@ struct [[nodiscard]] CBoxedSlice {
 static_assert(std::is_trivial<CBoxedSlice<int>>::value);
 static_assert(std::is_standard_layout<CBoxedSlice<int>>::value);
 
+// Compares strings by contents like `&str` in Rust. An empty string may have a dangling pointer, so it is never read.
+inline bool _char_str_equal(const char* a, usize a_size, const char* b, usize b_size) noexcept {
+    return a_size == b_size && (a_size == 0 || std::memcmp(a, b, a_size) == 0);
+}
+
+// Lexicographical comparison by bytes like `Ord for str` in Rust.
+inline bool _char_str_less(const char* a, usize a_size, const char* b, usize b_size) noexcept {
+    const usize size = a_size < b_size ? a_size : b_size;
+    const int result = size == 0 ? 0 : std::memcmp(a, b, size);
+    return result < 0 || (result == 0 && a_size < b_size);
+}
+
 /// C++ unsafe counterpart of Rust `&str`.
 ///
 /// Because `StrRef` in C++ side doesn't have any UTF-8 validation checking,
@@ -784,6 +804,17 @@ struct CharStrRef {
         return {data(), size()};
     }
 
+    // comparison by contents, also for `StrRef` and `BoxedStr`
+    bool operator==(const CharStrRef& other) const noexcept {
+        return _char_str_equal(this->_data, this->_size, other._data, other._size);
+    }
+    bool operator!=(const CharStrRef& other) const noexcept {
+        return !(*this == other);
+    }
+    bool operator<(const CharStrRef& other) const noexcept {
+        return _char_str_less(this->_data, this->_size, other._data, other._size);
+    }
+
 #if __cpp_lib_span
     /// Returns the slice as a `std::span`.
     std::span<element_type> span() const noexcept {
@@ -818,6 +849,22 @@ static_assert(sizeof(SliceRef<char>) == sizeof(CharStrRef));
 static_assert(std::is_trivial<CharStrRef>::value);
 static_assert(std::is_standard_layout<CharStrRef>::value);
 
+/// C++ counterpart of Rust `CharStrMutRef`, a writable char buffer to be modified in place by Rust side.
+///
+/// @warning Never create it over read-only memory like string literals.
+struct CharStrMutRef : public MutSliceRef<char> {
+    using MutSliceRef<char>::MutSliceRef;
+    CharStrMutRef(std::nullptr_t) noexcept : MutSliceRef<char>() {}
+
+    /// Returns a read-only view of the buffer.
+    CharStrRef as_char_str() const noexcept {
+        return CharStrRef(this->_data, this->_size);
+    }
+};
+static_assert(sizeof(CharStrMutRef) == sizeof(CharStrRef));
+static_assert(std::is_trivially_copyable<CharStrMutRef>::value);
+static_assert(std::is_standard_layout<CharStrMutRef>::value);
+
 /// C++ counterpart of Rust `&str`.
 ///
 /// Because `StrRef` in C++ side doesn't have any UTF-8 validation checking,
@@ -858,6 +905,17 @@ struct CStrRef {
         s._size = this->_size;
         return s;
     }
+
+    // comparison by contents
+    bool operator==(const CStrRef& other) const noexcept {
+        return _char_str_equal(this->_data, this->_size, other._data, other._size);
+    }
+    bool operator!=(const CStrRef& other) const noexcept {
+        return !(*this == other);
+    }
+    bool operator<(const CStrRef& other) const noexcept {
+        return _char_str_less(this->_data, this->_size, other._data, other._size);
+    }
 };
 static_assert(std::is_trivial<CStrRef>::value);
 static_assert(std::is_standard_layout<CStrRef>::value);
@@ -958,6 +1016,17 @@ struct [[nodiscard]] CBoxedStr {
         this->_size = 0;
         return range;
     }
+
+    // comparison by contents
+    bool operator==(const CBoxedStr& other) const noexcept {
+        return _char_str_equal(this->_data, this->_size, other._data, other._size);
+    }
+    bool operator!=(const CBoxedStr& other) const noexcept {
+        return !(*this == other);
+    }
+    bool operator<(const CBoxedStr& other) const noexcept {
+        return _char_str_less(this->_data, this->_size, other._data, other._size);
+    }
 };
 static_assert(std::is_trivial<CBoxedStr>::value);
 static_assert(std::is_standard_layout<CBoxedStr>::value);
@@ -1001,6 +1070,111 @@ inline StrRef MutSliceRef<const uint8_t>::as_str_unchecked<const uint8_t>() cons
 #undef SAFE_R
 #undef EMPTY_SLICE_BEGIN
 
+}  // namespace ffi_types
+namespace ffi_types {
+
+/// C++ counterpart of Rust `FfiResultTag`.
+enum class FfiResultTag : uint8_t {
+    Ok = 0,
+    Err = 1,
+};
+
+/// C++ wrapper for Rust `FfiResult<T, E>` with C ABI compatible layout.
+///
+/// The active member of the union is chosen by `tag`. Both values are Rust boxes.
+///
+/// @warning This type does *NOT* implement a safe destructor.
+///          To avoid leak, take the value by `ok()` or `err()`, or pass it back to Rust side.
+template <typename T, typename E>
+struct [[nodiscard]] FfiResult {
+    FfiResultTag tag;
+    union {
+        T* _ok;
+        E* _err;
+    };
+
+    // observers
+    bool is_ok() const noexcept {
+        return this->tag == FfiResultTag::Ok;
+    }
+    bool is_err() const noexcept {
+        return this->tag == FfiResultTag::Err;
+    }
+
+    /// Takes the `Ok` value as an owned box.
+    /// The result must be `Ok` and must not be passed to Rust side afterward.
+    OptionBox<T> ok() noexcept {
+        assert(this->is_ok());
+        auto box = OptionBox<T>(nullptr);
+        box._ptr = this->_ok;
+        this->_ok = nullptr;
+        return box;
+    }
+
+    /// Takes the `Err` value as an owned box.
+    /// The result must be `Err` and must not be passed to Rust side afterward.
+    OptionBox<E> err() noexcept {
+        assert(this->is_err());
+        auto box = OptionBox<E>(nullptr);
+        box._ptr = this->_err;
+        this->_err = nullptr;
+        return box;
+    }
+};
+static_assert(sizeof(FfiResult<int, int>) == sizeof(usize) * 2);
+static_assert(std::is_trivial<FfiResult<int, int>>::value);
+static_assert(std::is_standard_layout<FfiResult<int, int>>::value);
+
+}  // namespace ffi_types
+namespace ffi_types {
+
+/// C++ counterpart of Rust `FfiOption<T>` for small trivially copyable values.
+///
+/// The layout is `{ uint8_t has_value; T value; }` with the usual C padding after `has_value` up to `alignof(T)`.
+/// `_value` is uninitialized unless `_has_value` is set.
+template <typename T>
+struct FfiOption {
+    static_assert(std::is_trivially_copyable<T>::value, "FfiOption requires a trivially copyable type");
+
+    uint8_t _has_value;
+    union {
+        T _value;
+    };
+
+    static FfiOption some(T value) noexcept {
+        FfiOption option;
+        option._has_value = 1;
+        option._value = value;
+        return option;
+    }
+    static FfiOption none() noexcept {
+        FfiOption option;
+        option._has_value = 0;
+        return option;
+    }
+
+    // observers
+    bool has_value() const noexcept {
+        return this->_has_value != 0;
+    }
+    explicit operator bool() const noexcept {
+        return this->has_value();
+    }
+    /// The option must have a value.
+    const T& value() const noexcept {
+        assert(this->has_value());
+        return this->_value;
+    }
+    T value_or(T default_value) const noexcept {
+        return this->has_value() ? this->_value : default_value;
+    }
+};
+static_assert(sizeof(FfiOption<uint32_t>) == 8);
+static_assert(offsetof(FfiOption<uint32_t>, _value) == 4);
+static_assert(sizeof(FfiOption<bool>) == 2);
+static_assert(std::is_trivial<FfiOption<uint32_t>>::value);
+static_assert(std::is_standard_layout<FfiOption<uint32_t>>::value);
+
 }  // namespace ffi_types
 #pragma once
 
@@ -1010,17 +1184,45 @@ inline StrRef MutSliceRef<const uint8_t>::as_str_unchecked<const uint8_t>() cons
 
 namespace ffi_types {
 
+/// Version of the `#[repr(C)]` layouts of the types in this crate.
+///
+/// This is bumped whenever any layout changes. The C++ header has the same constant generated from this.
+/// Compare it with `_rust_ffi_abi_version()` at startup to catch a header and a library from different versions,
+/// e.g. `assert(ffi_types::abi_version_matches())` in C++.
+constexpr static const uint32_t FFI_TYPES_ABI_VERSION = 2;
+
 extern "C" {
 
 void _rust_ffi_boxed_str_drop(ffi_types::CBoxedStr _string);
 
 void _rust_ffi_boxed_bytes_drop(ffi_types::CBoxedSlice<uint8_t> _slice);
 
+void _rust_ffi_option_box_str_drop(ffi_types::COptionBox<ffi_types::BoxedStr> boxed);
+
+void _rust_ffi_option_box_bytes_drop(ffi_types::COptionBox<ffi_types::BoxedSlice<uint8_t>> boxed);
+
+/// Returns the length of `slice` without relying on the struct layout of the header.
+///
+/// This is a fallback for a header out of sync with the library and a symbol to link-check against.
+uintptr_t _rust_ffi_slice_len(ffi_types::CByteSliceRef slice);
+
+/// Returns the length of `s` in bytes. See `_rust_ffi_slice_len`.
+uintptr_t _rust_ffi_str_len(ffi_types::CStrRef s);
+
+/// Returns `FFI_TYPES_ABI_VERSION` of the linked library.
+uint32_t _rust_ffi_abi_version();
+
 } // extern "C"
 
 } // namespace ffi_types
 namespace ffi_types {
 
+/// Returns whether the linked Rust library has the same layouts as this header.
+/// Check it at startup to detect a header and a library from different versions.
+inline bool abi_version_matches() noexcept {
+    return ffi_types::_rust_ffi_abi_version() == ffi_types::FFI_TYPES_ABI_VERSION;
+}
+
 inline void BoxedStr::_drop() noexcept {
     ffi_types::_rust_ffi_boxed_str_drop(CBoxedStr::from(std::move(*this)));
 }
@@ -1030,6 +1232,16 @@ inline void BoxedSlice<uint8_t>::_drop() noexcept {
     ffi_types::_rust_ffi_boxed_bytes_drop(CBoxedSlice<uint8_t>::from(std::move(*this)));
 }
 
+template <>
+inline void OptionBox<BoxedStr>::_drop() noexcept {
+    ffi_types::_rust_ffi_option_box_str_drop(COptionBox<BoxedStr>::from(std::move(*this)));
+}
+
+template <>
+inline void OptionBox<BoxedSlice<uint8_t>>::_drop() noexcept {
+    ffi_types::_rust_ffi_option_box_bytes_drop(COptionBox<BoxedSlice<uint8_t>>::from(std::move(*this)));
+}
+
 }  // namespace ffi_types
 #undef _COPY_DELETE
 
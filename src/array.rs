@@ -0,0 +1,114 @@
+use crate::{Array, SliceRef};
+
+/// Rust wrapper for &[T; N].
+///
+/// Unlike [`SliceRef`], the length is a part of the type and only a pointer is passed.
+/// The C++ header has the same name for `const Array<T, N>*`.
+#[repr(transparent)]
+pub struct ArrayRef<T: 'static, const N: usize>(*const Array<T, N>);
+static_assertions::assert_eq_size!(ArrayRef<u8, 4>, &[u8; 4]);
+static_assertions::assert_eq_size!(ArrayRef<u64, 0>, &[u64; 0]);
+// SAFETY: same as &[T; N]
+unsafe impl<T: Sync, const N: usize> Send for ArrayRef<T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for ArrayRef<T, N> {}
+static_assertions::assert_impl_all!(ArrayRef<u8, 4>: Send, Sync);
+
+impl<T, const N: usize> Clone for ArrayRef<T, N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for ArrayRef<T, N> {}
+
+impl<T, const N: usize> ArrayRef<T, N> {
+    /// Create a new wrapper for a static array `&'static [T; N]`.
+    /// See [`ArrayRef::new_unbound`] to remove lifetime bound.
+    #[inline(always)]
+    pub const fn new(array: &'static [T; N]) -> Self {
+        Self(array)
+    }
+
+    /// Create a new wrapper for an array `&[T; N]`.
+    /// `unbound` means bounded lifetime will be removed to be static.
+    ///
+    /// # Safety
+    /// The returned object must not outlive the given array.
+    #[inline(always)]
+    pub unsafe fn new_unbound(array: &'_ [T; N]) -> Self {
+        Self::new(crate::into_static(array))
+    }
+
+    #[inline(always)]
+    pub const fn as_array(&self) -> &'static [T; N] {
+        unsafe { &*self.0 } // SAFETY: `self.0` is created from a valid reference
+    }
+
+    /// Returns a wrapper of the same buffer with the length at runtime.
+    #[inline(always)]
+    pub const fn as_slice_ref(&self) -> SliceRef<T> {
+        SliceRef::new(self.as_array())
+    }
+}
+
+impl<T, const N: usize> From<&'static [T; N]> for ArrayRef<T, N> {
+    #[inline(always)]
+    fn from(array: &'static [T; N]) -> Self {
+        Self::new(array)
+    }
+}
+
+impl<T, const N: usize> From<ArrayRef<T, N>> for SliceRef<T> {
+    #[inline(always)]
+    fn from(array: ArrayRef<T, N>) -> Self {
+        array.as_slice_ref()
+    }
+}
+
+/// Fails when the length of the slice is not `N`.
+impl<T, const N: usize> TryFrom<SliceRef<T>> for ArrayRef<T, N> {
+    type Error = core::array::TryFromSliceError;
+
+    #[inline]
+    fn try_from(slice: SliceRef<T>) -> Result<Self, Self::Error> {
+        <&[T; N]>::try_from(slice.into_slice()).map(Self::new)
+    }
+}
+
+impl<T, const N: usize> core::ops::Deref for ArrayRef<T, N> {
+    type Target = [T; N];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_array()
+    }
+}
+
+impl<T, const N: usize> core::convert::AsRef<[T; N]> for ArrayRef<T, N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T; N] {
+        self.as_array()
+    }
+}
+
+#[test]
+fn test_array_ref() {
+    static KEY: [u8; 4] = *b"key!";
+    let key = ArrayRef::new(&KEY);
+    assert_eq!(key.len(), 4);
+    assert_eq!(*key, *b"key!");
+    assert_eq!(key.as_slice_ref().into_slice(), b"key!");
+
+    let slice = SliceRef::new(&[1u32, 2, 3]);
+    let array = ArrayRef::<u32, 3>::try_from(slice).ok().unwrap();
+    assert_eq!(array.as_array(), &[1, 2, 3]);
+    assert!(ArrayRef::<u32, 2>::try_from(slice).is_err());
+
+    let local = [7u16; 2];
+    let array = unsafe { ArrayRef::new_unbound(&local) };
+    assert_eq!(array.iter().sum::<u16>(), 14);
+
+    let empty = ArrayRef::<u64, 0>::new(&[]);
+    assert!(SliceRef::from(empty).is_empty());
+}
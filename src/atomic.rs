@@ -0,0 +1,147 @@
+use crate::OptionBox;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// [`OptionBox<T>`] which can be replaced from multiple threads.
+///
+/// The layout is the same as [`OptionBox<T>`].
+/// Ownership moves in and out atomically, so every value is freed exactly once.
+/// There is no way to borrow the contained value, because another thread may take it in the meantime.
+#[repr(transparent)]
+pub struct AtomicOptionBox<T> {
+    ptr: AtomicPtr<T>,
+}
+static_assertions::assert_eq_size!(AtomicOptionBox<u8>, OptionBox<u8>);
+static_assertions::assert_impl_all!(AtomicOptionBox<u8>: Send, Sync);
+static_assertions::assert_not_impl_any!(AtomicOptionBox<alloc::rc::Rc<u8>>: Send, Sync);
+
+// SAFETY: the value is only moved between threads, never shared
+unsafe impl<T: Send> Send for AtomicOptionBox<T> {}
+unsafe impl<T: Send> Sync for AtomicOptionBox<T> {}
+
+impl<T> AtomicOptionBox<T> {
+    #[inline(always)]
+    pub fn new(value: OptionBox<T>) -> Self {
+        Self {
+            ptr: AtomicPtr::new(value.into_raw()),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn none() -> Self {
+        Self {
+            ptr: AtomicPtr::new(core::ptr::null_mut()),
+        }
+    }
+
+    /// Stores `value` and returns the previous one.
+    #[inline]
+    pub fn swap(&self, value: OptionBox<T>) -> OptionBox<T> {
+        let old = self.ptr.swap(value.into_raw(), Ordering::AcqRel);
+        // SAFETY: the pointer was taken from an `OptionBox<T>` and is owned by nobody else after the swap
+        unsafe { OptionBox::from_raw(old) }
+    }
+
+    /// Stores `value` and drops the previous one.
+    #[inline]
+    pub fn store(&self, value: OptionBox<T>) {
+        drop(self.swap(value).into_box());
+    }
+
+    /// Takes the value out, leaving none.
+    #[inline]
+    pub fn take(&self) -> OptionBox<T> {
+        self.swap(OptionBox::none())
+    }
+
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.ptr.load(Ordering::Acquire).is_null()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> OptionBox<T> {
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: `self` is consumed, so the pointer is not owned by it anymore
+        unsafe { OptionBox::from_raw(this.ptr.load(Ordering::Acquire)) }
+    }
+}
+
+impl<T> Drop for AtomicOptionBox<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: `&mut self` guarantees no other access
+        drop(unsafe { OptionBox::from_raw(*self.ptr.get_mut()) }.into_box());
+    }
+}
+
+impl<T> Default for AtomicOptionBox<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<T> From<OptionBox<T>> for AtomicOptionBox<T> {
+    #[inline(always)]
+    fn from(value: OptionBox<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+#[test]
+fn test_atomic_option_box() {
+    let atomic = AtomicOptionBox::none();
+    assert!(atomic.is_none());
+    assert!(atomic.swap(OptionBox::from_value(1)).into_box().is_none());
+    assert_eq!(
+        atomic.swap(OptionBox::from_value(2)).into_box().as_deref(),
+        Some(&1)
+    );
+    atomic.store(OptionBox::from_value(3));
+    assert_eq!(atomic.take().into_box().as_deref(), Some(&3));
+    assert!(atomic.take().into_box().is_none());
+
+    atomic.store(OptionBox::from_value(4));
+    assert_eq!(atomic.into_inner().into_box().as_deref(), Some(&4));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_atomic_option_box_threads() {
+    use core::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+
+    struct Counted(Arc<AtomicUsize>);
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    const THREADS: usize = 8;
+    const SWAPS: usize = 1000;
+    let drops = Arc::new(AtomicUsize::new(0));
+    let atomic = Arc::new(AtomicOptionBox::none());
+    let threads: alloc::vec::Vec<_> = (0..THREADS)
+        .map(|_| {
+            let atomic = atomic.clone();
+            let drops = drops.clone();
+            std::thread::spawn(move || {
+                for i in 0..SWAPS {
+                    let value = OptionBox::from_value(Counted(drops.clone()));
+                    if i % 2 == 0 {
+                        drop(atomic.swap(value).into_box());
+                    } else {
+                        atomic.store(value);
+                    }
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(drops.load(Ordering::Relaxed), THREADS * SWAPS - 1);
+    drop(atomic);
+    assert_eq!(drops.load(Ordering::Relaxed), THREADS * SWAPS);
+}
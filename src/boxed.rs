@@ -1,7 +1,22 @@
-/// A type alias for `std::boxed::Box<T>`.
+/// A type alias for `alloc::boxed::Box<T>`.
 ///
 /// `Box` doesn't require a wrapper because it is guaranteed to be layout as a pointer.
-pub type Box<T> = std::boxed::Box<T>;
+pub type Box<T> = alloc::boxed::Box<T>;
+
+/// Same as [`Box::into_raw`], to pass an owned value to the other side.
+#[inline(always)]
+pub fn box_into_raw<T>(boxed: Box<T>) -> *mut T {
+    Box::into_raw(boxed)
+}
+
+/// Same as [`Box::from_raw`], inverse of [`box_into_raw`].
+///
+/// # Safety
+/// `ptr` must be a pointer created by `Box<T>` and not owned by any other box.
+#[inline(always)]
+pub unsafe fn box_from_raw<T>(ptr: *mut T) -> Box<T> {
+    Box::from_raw(ptr)
+}
 
 /// A type alias for `Option<Box<T>>`.
 ///
@@ -13,6 +28,11 @@ pub struct OptionBox<T> {
 static_assertions::assert_eq_size!(OptionBox<u8>, *const u8);
 static_assertions::assert_eq_size!(OptionBox<u8>, Box<u8>);
 static_assertions::assert_eq_size!(OptionBox<u8>, Option<Box<u8>>);
+// SAFETY: same as Option<Box<T>>
+unsafe impl<T: Send> Send for OptionBox<T> {}
+unsafe impl<T: Sync> Sync for OptionBox<T> {}
+static_assertions::assert_impl_all!(OptionBox<u8>: Send, Sync);
+static_assertions::assert_not_impl_any!(OptionBox<alloc::rc::Rc<u8>>: Send, Sync);
 
 impl<T> OptionBox<T> {
     #[inline(always)]
@@ -35,17 +55,225 @@ impl<T> OptionBox<T> {
         Some(unsafe { Box::from_raw(self.ptr) })
     }
 
+    /// Consumes the box and returns the raw pointer, which is null for `none()`.
+    ///
+    /// The value is leaked unless the pointer is given back to [`OptionBox::from_raw`].
+    #[inline(always)]
+    pub const fn into_raw(self) -> *mut T {
+        self.ptr
+    }
+
+    /// Inverse of [`OptionBox::into_raw`]. A null pointer results in `none()`.
+    ///
+    /// # Safety
+    /// `ptr` must be either null or a pointer created by `Box<T>` and not owned by any other box.
+    #[inline(always)]
+    pub const unsafe fn from_raw(ptr: *mut T) -> Self {
+        Self { ptr }
+    }
+
+    /// Takes the ownership of a pointer from a library using `NonNull<T>` for owned handles.
+    /// Inverse of `TryFrom<OptionBox<T>> for NonNull<T>`.
+    ///
+    /// This is not a `From` impl because the ownership is assumed; the value is freed when the box is dropped.
+    ///
+    /// # Safety
+    /// `ptr` must be created by `Box<T>` with the global allocator, and the other side must not use or free it afterwards.
+    #[inline(always)]
+    pub const unsafe fn from_non_null(ptr: core::ptr::NonNull<T>) -> Self {
+        Self { ptr: ptr.as_ptr() }
+    }
+
+    /// Returns the pointer without moving the ownership, or `None` for `none()`.
+    ///
+    /// `self` still owns the value, so the pointer must not be freed and must not outlive `self`.
+    #[inline(always)]
+    pub fn as_non_null(&self) -> Option<core::ptr::NonNull<T>> {
+        core::ptr::NonNull::new(self.ptr)
+    }
+
     #[inline(always)]
     pub const fn none() -> Self {
         Self {
-            ptr: std::ptr::null_mut(),
+            ptr: core::ptr::null_mut(),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn is_some(&self) -> bool {
+        !self.ptr.is_null()
+    }
+
+    #[inline(always)]
+    pub const fn is_none(&self) -> bool {
+        self.ptr.is_null()
+    }
+
+    /// Returns the bytes of the box allocation, `size_of::<T>()`, or 0 for `none()`. The value is not read.
+    ///
+    /// Only the direct allocation is counted; e.g. the buffer of a boxed `BoxedSlice` is not included.
+    #[inline(always)]
+    pub const fn heap_size(&self) -> usize {
+        if self.is_some() {
+            core::mem::size_of::<T>()
+        } else {
+            0
         }
     }
 
+    /// Borrows the value like `Option::as_deref`.
+    ///
+    /// There is no `Deref<Target = Option<Box<T>>>` or `AsRef<Option<Box<T>>>`,
+    /// because the value is stored as a raw pointer rather than an `Option<Box<T>>`.
     #[inline]
     pub fn as_ref(&self) -> Option<&T> {
         unsafe { self.ptr.as_ref() } // SAFETY: `ptr` is a value of a valid Box
     }
+
+    /// Mutably borrows the value like `Option::as_deref_mut`. See [`OptionBox::as_ref`].
+    #[inline]
+    pub fn as_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.ptr.as_mut() } // SAFETY: `ptr` is a value of a valid Box
+    }
+
+    /// Keeps the value only if `pred` returns `true`, like [`Option::filter`].
+    ///
+    /// A discarded value is dropped and its allocation is freed.
+    #[inline]
+    pub fn filter<P: FnOnce(&T) -> bool>(self, pred: P) -> OptionBox<T> {
+        self.into_box().filter(|boxed| pred(boxed)).into()
+    }
+
+    /// Transforms into `Result`, mapping a null pointer to `Err(err)` like [`Option::ok_or`].
+    #[inline]
+    pub fn ok_or<E>(self, err: E) -> Result<Box<T>, E> {
+        self.into_box().ok_or(err)
+    }
+
+    /// Returns an iterator over the possibly contained value, like [`Option::iter`].
+    #[inline]
+    pub fn iter(&self) -> core::option::IntoIter<&T> {
+        self.as_ref().into_iter()
+    }
+
+    /// Boxes `value` if `self` is null, then returns a mutable reference to the contained value.
+    #[inline]
+    pub fn get_or_insert(&mut self, value: T) -> &mut T {
+        self.get_or_insert_with(|| value)
+    }
+
+    /// Boxes the value returned by `f` if `self` is null, then returns a mutable reference to the contained value.
+    #[inline]
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, f: F) -> &mut T {
+        if self.ptr.is_null() {
+            self.ptr = Box::into_raw(Box::new(f()));
+        }
+        unsafe { &mut *self.ptr } // SAFETY: `ptr` is a value of a valid Box
+    }
+}
+
+/// A non-null counterpart of [`OptionBox<T>`] for a value which is always present.
+///
+/// The layout is a non-null pointer, so `Option<NonNullBox<T>>` is a pointer as well.
+/// Like [`OptionBox<T>`], the value is not dropped automatically; take it back by [`NonNullBox::into_box`].
+#[repr(transparent)]
+pub struct NonNullBox<T> {
+    ptr: core::ptr::NonNull<T>,
+}
+static_assertions::assert_eq_size!(NonNullBox<u8>, *const u8);
+static_assertions::assert_eq_size!(Option<NonNullBox<u8>>, *const u8);
+// SAFETY: same as Box<T>
+unsafe impl<T: Send> Send for NonNullBox<T> {}
+unsafe impl<T: Sync> Sync for NonNullBox<T> {}
+static_assertions::assert_impl_all!(NonNullBox<u8>: Send, Sync);
+
+impl<T> NonNullBox<T> {
+    #[inline(always)]
+    pub fn new(boxed: Box<T>) -> Self {
+        // SAFETY: `Box::into_raw` never returns null
+        let ptr = unsafe { core::ptr::NonNull::new_unchecked(Box::into_raw(boxed)) };
+        Self { ptr }
+    }
+
+    #[inline(always)]
+    pub fn from_value(value: T) -> Self {
+        Self::new(Box::new(value))
+    }
+
+    #[inline(always)]
+    pub fn into_box(self) -> Box<T> {
+        unsafe { Box::from_raw(self.ptr.as_ptr()) } // SAFETY: `ptr` is a value of a valid Box
+    }
+
+    #[inline(always)]
+    pub const fn into_raw(self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// Inverse of [`NonNullBox::into_raw`]. A null pointer results in `None`.
+    ///
+    /// # Safety
+    /// `ptr` must be either null or a pointer created by `Box<T>` and not owned by any other box.
+    #[inline(always)]
+    pub unsafe fn from_raw(ptr: *mut T) -> Option<Self> {
+        core::ptr::NonNull::new(ptr).map(|ptr| Self { ptr })
+    }
+}
+
+impl<T> core::ops::Deref for NonNullBox<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        unsafe { self.ptr.as_ref() } // SAFETY: `ptr` is a value of a valid Box
+    }
+}
+
+impl<T> core::ops::DerefMut for NonNullBox<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { self.ptr.as_mut() } // SAFETY: `ptr` is a value of a valid Box
+    }
+}
+
+impl<T> From<Box<T>> for NonNullBox<T> {
+    #[inline]
+    fn from(boxed: Box<T>) -> Self {
+        Self::new(boxed)
+    }
+}
+
+impl<T> From<NonNullBox<T>> for OptionBox<T> {
+    #[inline]
+    fn from(boxed: NonNullBox<T>) -> Self {
+        Self {
+            ptr: boxed.into_raw(),
+        }
+    }
+}
+
+/// Moves the ownership into the returned pointer, e.g. to hand it to a library using `NonNull<T>` for owned handles.
+///
+/// The box is forgotten, so the value is leaked unless the pointer is given back to [`OptionBox::from_non_null`].
+/// Fails for `none()`, giving it back.
+impl<T> TryFrom<OptionBox<T>> for core::ptr::NonNull<T> {
+    type Error = OptionBox<T>;
+
+    #[inline]
+    fn try_from(boxed: OptionBox<T>) -> Result<Self, Self::Error> {
+        core::ptr::NonNull::new(boxed.ptr).ok_or(boxed)
+    }
+}
+
+/// Deep-clones the value into a new box like `Option<Box<T>>`. A null pointer stays null.
+impl<T: Clone> Clone for OptionBox<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        match self.as_ref() {
+            Some(value) => Self::from_value(value.clone()),
+            None => Self::none(),
+        }
+    }
 }
 
 impl<T> From<Box<T>> for OptionBox<T> {
@@ -66,25 +294,169 @@ impl<T> From<Option<Box<T>>> for OptionBox<T> {
     }
 }
 
-// impl<T> std::convert::AsRef<Option<Box<T>>> for OptionBox<T> {
-//     #[inline(always)]
-//     fn as_ref(&self) -> &Option<Box<T>> {
-//         &self.0
-//     }
-// }
+/// Takes the value out of the box, freeing the allocation.
+impl<T> IntoIterator for OptionBox<T> {
+    type Item = T;
+    type IntoIter = core::option::IntoIter<T>;
 
-// impl<T> std::borrow::Borrow<Option<Box<T>>> for OptionBox<T> {
-//     #[inline(always)]
-//     fn borrow(&self) -> &Option<Box<T>> {
-//         self.as_ref()
-//     }
-// }
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_box().map(|boxed| *boxed).into_iter()
+    }
+}
 
-// impl<T> std::ops::Deref for OptionBox<T> {
-//     type Target = Option<Box<T>>;
+impl<'a, T> IntoIterator for &'a OptionBox<T> {
+    type Item = &'a T;
+    type IntoIter = core::option::IntoIter<&'a T>;
 
-//     #[inline(always)]
-//     fn deref(&self) -> &Self::Target {
-//         self.as_ref()
-//     }
-// }
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[test]
+fn test_option_box_get_or_insert() {
+    let mut boxed = OptionBox::none();
+    assert_eq!(*boxed.get_or_insert(1), 1);
+    *boxed.get_or_insert(2) += 10;
+    assert_eq!(*boxed.get_or_insert_with(|| unreachable!()), 11);
+    assert_eq!(boxed.into_box().as_deref(), Some(&11));
+
+    let mut boxed = OptionBox::none();
+    boxed.get_or_insert_with(|| alloc::string::String::from("hello"));
+    assert_eq!(
+        boxed.into_box().as_deref().map(|s| s.as_str()),
+        Some("hello")
+    );
+}
+
+#[test]
+fn test_option_box_raw() {
+    let raw = OptionBox::from_value(alloc::string::String::from("handle")).into_raw();
+    assert!(!raw.is_null());
+    let boxed = unsafe { OptionBox::from_raw(raw) };
+    assert_eq!(boxed.as_ref().map(|s| s.as_str()), Some("handle"));
+    drop(boxed.into_box());
+
+    let raw = OptionBox::<u32>::none().into_raw();
+    assert!(raw.is_null());
+    assert!(unsafe { OptionBox::from_raw(raw) }.into_box().is_none());
+}
+
+#[test]
+fn test_option_box_iter() {
+    let boxes = alloc::vec![OptionBox::from_value(1), OptionBox::none()];
+    assert_eq!(boxes.iter().flat_map(|b| b.iter()).count(), 1);
+    let values: alloc::vec::Vec<i32> = boxes.into_iter().flatten().collect();
+    assert_eq!(values, [1]);
+
+    let names = alloc::vec![
+        OptionBox::from_value(alloc::string::String::from("a")),
+        OptionBox::none(),
+        OptionBox::from_value("b".into()),
+    ];
+    assert_eq!(
+        names
+            .into_iter()
+            .flatten()
+            .collect::<alloc::string::String>(),
+        "ab"
+    );
+}
+
+#[test]
+fn test_option_box_ok_or() {
+    assert_eq!(OptionBox::from_value(1).ok_or("null").as_deref(), Ok(&1));
+    assert_eq!(OptionBox::<u32>::none().ok_or("null"), Err("null"));
+}
+
+#[test]
+fn test_option_box_filter() {
+    const NONE: OptionBox<u32> = OptionBox::none();
+    const _: () = assert!(NONE.is_none() && !NONE.is_some());
+
+    let boxed = OptionBox::from_value(2).filter(|v| v % 2 == 0);
+    assert!(boxed.is_some());
+    assert!(boxed.filter(|v| *v > 2).is_none());
+    assert!(OptionBox::<u32>::none().filter(|_| true).is_none());
+
+    let dropped = alloc::rc::Rc::new(());
+    let boxed = OptionBox::from_value(dropped.clone()).filter(|_| false);
+    assert!(boxed.is_none());
+    assert_eq!(alloc::rc::Rc::strong_count(&dropped), 1);
+}
+
+#[test]
+fn test_non_null_box() {
+    let mut boxed = NonNullBox::from_value(alloc::string::String::from("handle"));
+    boxed.push('!');
+    assert_eq!(boxed.as_str(), "handle!");
+
+    let raw = boxed.into_raw();
+    let boxed = unsafe { NonNullBox::from_raw(raw) }.unwrap();
+    assert_eq!(*boxed.into_box(), "handle!");
+    assert!(unsafe { NonNullBox::<u32>::from_raw(core::ptr::null_mut()) }.is_none());
+
+    let option = OptionBox::from(NonNullBox::from_value(1));
+    assert_eq!(option.into_box().as_deref(), Some(&1));
+
+    let raw = box_into_raw(Box::new(2));
+    assert_eq!(*unsafe { box_from_raw(raw) }, 2);
+}
+
+#[test]
+fn test_option_box_clone() {
+    // run under `cargo miri test` to check no double free or leak
+    let boxed = OptionBox::from_value(alloc::vec![alloc::string::String::from("a")]);
+    let cloned = boxed.clone();
+    assert_ne!(boxed.ptr, cloned.ptr);
+    assert_eq!(cloned.as_ref(), boxed.as_ref());
+    drop(boxed.into_box());
+    assert_eq!(cloned.into_box().as_deref().map(|v| v.len()), Some(1));
+
+    assert!(OptionBox::<alloc::string::String>::none().clone().is_none());
+}
+
+#[test]
+fn test_option_box_non_null() {
+    let boxed = OptionBox::from_value(7u32);
+    let borrowed = boxed.as_non_null().unwrap();
+    assert_eq!(unsafe { *borrowed.as_ref() }, 7);
+    assert!(OptionBox::<u32>::none().as_non_null().is_none());
+
+    let handle = core::ptr::NonNull::try_from(boxed).ok().unwrap();
+    assert_eq!(handle, borrowed);
+    let boxed = unsafe { OptionBox::from_non_null(handle) };
+    assert_eq!(*boxed.into_box().unwrap(), 7);
+
+    let failed = core::ptr::NonNull::try_from(OptionBox::<u32>::none());
+    assert!(failed.err().unwrap().is_none());
+}
+
+#[test]
+fn test_option_box_heap_size() {
+    let boxed = OptionBox::from_value(1u64);
+    assert_eq!(boxed.heap_size(), 8);
+    drop(boxed.into_box());
+    assert_eq!(OptionBox::<u64>::none().heap_size(), 0);
+
+    let nested = OptionBox::from_value(crate::BoxedSlice::from([0u8; 64]));
+    assert_eq!(
+        nested.heap_size(),
+        core::mem::size_of::<crate::BoxedSlice<u8>>()
+    );
+    drop(nested.into_box());
+}
+
+#[test]
+fn test_option_box_as_mut() {
+    let mut boxed = OptionBox::from_value(alloc::vec![1, 2]);
+    boxed.as_mut().unwrap().push(3);
+    assert_eq!(boxed.as_ref().map(|v| v.len()), Some(3));
+    assert_eq!(*boxed.into_box().unwrap(), [1, 2, 3]);
+
+    let mut none = OptionBox::<u8>::none();
+    assert!(none.as_mut().is_none());
+    assert!(none.as_ref().is_none());
+}
@@ -0,0 +1,657 @@
+use crate::slice::SliceInner;
+use crate::{BoxedSlice, BoxedStr, ByteSliceRef, MutSliceRef, SliceRef, StrRef};
+
+/// Helpers to parse binary data.
+///
+/// Every method checks bounds against the stored length and returns `None` instead of panicking.
+impl ByteSliceRef {
+    #[inline(always)]
+    fn read_array<const N: usize>(&self, offset: usize) -> Option<[u8; N]> {
+        let end = offset.checked_add(N)?;
+        let bytes = self.get(offset..end)?;
+        <[u8; N]>::try_from(bytes).ok()
+    }
+
+    /// Reads a little-endian `u16` at `offset`.
+    #[inline]
+    pub fn read_u16_le(&self, offset: usize) -> Option<u16> {
+        self.read_array(offset).map(u16::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u16` at `offset`.
+    #[inline]
+    pub fn read_u16_be(&self, offset: usize) -> Option<u16> {
+        self.read_array(offset).map(u16::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u32` at `offset`.
+    #[inline]
+    pub fn read_u32_le(&self, offset: usize) -> Option<u32> {
+        self.read_array(offset).map(u32::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u32` at `offset`.
+    #[inline]
+    pub fn read_u32_be(&self, offset: usize) -> Option<u32> {
+        self.read_array(offset).map(u32::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u64` at `offset`.
+    #[inline]
+    pub fn read_u64_le(&self, offset: usize) -> Option<u64> {
+        self.read_array(offset).map(u64::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u64` at `offset`.
+    #[inline]
+    pub fn read_u64_be(&self, offset: usize) -> Option<u64> {
+        self.read_array(offset).map(u64::from_be_bytes)
+    }
+
+    #[inline]
+    fn read_slice<T, const N: usize>(&self, f: fn([u8; N]) -> T) -> Option<BoxedSlice<T>> {
+        let bytes = self.0.checked_slice();
+        if !bytes.len().is_multiple_of(N) {
+            return None;
+        }
+        // chunks are copied out by value, so the buffer doesn't need to be aligned for `T`
+        let chunks = bytes.chunks_exact(N);
+        Some(chunks.map(|chunk| f(chunk.try_into().unwrap())).collect())
+    }
+
+    /// Reads the whole buffer as little-endian `u16`s, or returns `None` if the length is not a multiple of 2.
+    #[inline]
+    pub fn read_u16_slice_le(&self) -> Option<BoxedSlice<u16>> {
+        self.read_slice(u16::from_le_bytes)
+    }
+
+    /// Reads the whole buffer as big-endian `u16`s, or returns `None` if the length is not a multiple of 2.
+    #[inline]
+    pub fn read_u16_slice_be(&self) -> Option<BoxedSlice<u16>> {
+        self.read_slice(u16::from_be_bytes)
+    }
+
+    /// Reads the whole buffer as little-endian `u32`s, or returns `None` if the length is not a multiple of 4.
+    #[inline]
+    pub fn read_u32_slice_le(&self) -> Option<BoxedSlice<u32>> {
+        self.read_slice(u32::from_le_bytes)
+    }
+
+    /// Reads the whole buffer as big-endian `u32`s, or returns `None` if the length is not a multiple of 4.
+    #[inline]
+    pub fn read_u32_slice_be(&self) -> Option<BoxedSlice<u32>> {
+        self.read_slice(u32::from_be_bytes)
+    }
+
+    /// Reads the whole buffer as little-endian `u64`s, or returns `None` if the length is not a multiple of 8.
+    #[inline]
+    pub fn read_u64_slice_le(&self) -> Option<BoxedSlice<u64>> {
+        self.read_slice(u64::from_le_bytes)
+    }
+
+    /// Reads the whole buffer as big-endian `u64`s, or returns `None` if the length is not a multiple of 8.
+    #[inline]
+    pub fn read_u64_slice_be(&self) -> Option<BoxedSlice<u64>> {
+        self.read_slice(u64::from_be_bytes)
+    }
+
+    /// Reinterprets the bytes as `[U]` in place, like `bytemuck::try_cast_slice`.
+    ///
+    /// Returns `None` unless the pointer is aligned for `U` and the length is a multiple of `size_of::<U>()`,
+    /// so a misaligned reference is never created. Always `None` for a zero-sized `U`.
+    /// An empty buffer results in an empty slice regardless of the pointer.
+    ///
+    /// # Safety
+    /// Every bit pattern must be a valid `U`, e.g. integers or a `#[repr(C)]` struct of them.
+    /// Types like `bool`, `char`, enums and references are not.
+    #[inline]
+    pub unsafe fn cast_slice<U: Copy + 'static>(&self) -> Option<SliceRef<U>> {
+        let size = core::mem::size_of::<U>();
+        if size == 0 || !self.0.len.is_multiple_of(size) {
+            return None;
+        }
+        if self.0.len == 0 {
+            return Some(SliceRef::default());
+        }
+        let ptr = self.0.ptr as *mut U;
+        if !ptr.is_aligned() {
+            return None;
+        }
+        Some(SliceRef(SliceInner {
+            ptr,
+            len: self.0.len / size,
+        }))
+    }
+
+    /// Splits the first `n` bytes from the rest, or returns `None` if shorter than `n`.
+    #[inline]
+    pub fn split_first_bytes(&self, n: usize) -> Option<(ByteSliceRef, ByteSliceRef)> {
+        if n > self.0.len {
+            return None;
+        }
+        let (head, tail) = self.0.checked_slice().split_at(n);
+        Some((Self::new(head), Self::new(tail)))
+    }
+
+    /// Same as [`str::lines`], but over bytes and yields [`ByteSliceRef`].
+    ///
+    /// Lines end with `\n` or `\r\n`, which are not included. The last line ending is optional,
+    /// so neither an empty buffer nor a trailing newline yields an empty line.
+    /// The scan uses `memchr` with the `memchr` feature.
+    ///
+    /// The yielded values borrow the buffer of `self`, so they share its validity.
+    #[inline]
+    pub fn split_lines(&self) -> impl Iterator<Item = ByteSliceRef> {
+        let mut rest: &'static [u8] = self.0.checked_slice();
+        core::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+            let line = match find_newline(rest) {
+                Some(index) => {
+                    let line = &rest[..index];
+                    rest = &rest[index + 1..];
+                    line.strip_suffix(b"\r").unwrap_or(line)
+                }
+                None => core::mem::take(&mut rest),
+            };
+            Some(Self::new(line))
+        })
+    }
+
+    /// Converts into a [`StrRef`] over the same buffer after UTF-8 validation, without copying.
+    #[inline]
+    pub fn try_into_str_ref(self) -> Result<StrRef, core::str::Utf8Error> {
+        core::str::from_utf8(self.0.checked_slice())?;
+        Ok(StrRef(self.0))
+    }
+
+    /// Returns the index of the first `byte`.
+    #[inline]
+    pub fn position(&self, byte: u8) -> Option<usize> {
+        self.0.checked_slice().iter().position(|&b| b == byte)
+    }
+
+    #[inline]
+    pub fn contains(&self, byte: u8) -> bool {
+        self.position(byte).is_some()
+    }
+
+    #[inline]
+    pub fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.0.checked_slice().starts_with(prefix)
+    }
+
+    #[inline]
+    pub fn ends_with(&self, suffix: &[u8]) -> bool {
+        self.0.checked_slice().ends_with(suffix)
+    }
+
+    /// Returns the index of the first occurrence of `needle`. An empty `needle` is found at 0.
+    #[inline]
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        self.0
+            .checked_slice()
+            .windows(needle.len())
+            .position(|window| window == needle)
+    }
+}
+
+/// Consumes a [`ByteSliceRef`] from the front, e.g. to decode a binary protocol field by field.
+///
+/// Every method checks bounds against the stored length and returns `None` without advancing when too short.
+/// The cursor borrows the slice it was created from.
+pub struct ByteCursor<'a> {
+    bytes: ByteSliceRef,
+    offset: usize,
+    _marker: core::marker::PhantomData<&'a ByteSliceRef>,
+}
+
+impl<'a> ByteCursor<'a> {
+    #[inline(always)]
+    pub fn new(bytes: &'a ByteSliceRef) -> Self {
+        Self {
+            bytes: *bytes,
+            offset: 0,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Takes the next `n` bytes over the same buffer.
+    #[inline]
+    pub fn take(&mut self, n: usize) -> Option<ByteSliceRef> {
+        let end = self.offset.checked_add(n)?;
+        let bytes = self.bytes.subslice(self.offset..end)?;
+        self.offset = end;
+        Some(bytes)
+    }
+
+    /// Takes the next 4 bytes as a little-endian `u32`.
+    #[inline]
+    pub fn take_u32_le(&mut self) -> Option<u32> {
+        let value = self.bytes.read_u32_le(self.offset)?;
+        self.offset += 4;
+        Some(value)
+    }
+
+    /// Returns the number of bytes not consumed yet.
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+}
+
+/// An error decoding a hex string. See [`BoxedSlice::from_hex`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexError {
+    /// The string has an odd number of bytes.
+    OddLength,
+    /// The byte at `index` is not a hex digit.
+    InvalidDigit { index: usize },
+}
+
+impl core::fmt::Display for HexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OddLength => write!(f, "hex string has an odd length"),
+            Self::InvalidDigit { index } => write!(f, "invalid hex digit at {}", index),
+        }
+    }
+}
+
+impl core::error::Error for HexError {}
+
+impl ByteSliceRef {
+    /// Encodes the bytes as lowercase hex, e.g. to log a buffer from the other side.
+    /// An empty buffer results in an empty string.
+    #[inline]
+    pub fn to_hex(&self) -> BoxedStr {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let bytes = self.0.checked_slice();
+        let mut hex = alloc::string::String::with_capacity(bytes.len() * 2);
+        for &byte in bytes {
+            hex.push(DIGITS[(byte >> 4) as usize] as char);
+            hex.push(DIGITS[(byte & 0xf) as usize] as char);
+        }
+        BoxedStr::new(hex.into_boxed_str())
+    }
+}
+
+impl BoxedSlice<u8> {
+    /// Inverse of [`ByteSliceRef::to_hex`]. Both lowercase and uppercase digits are accepted.
+    #[inline]
+    pub fn from_hex(s: &str) -> Result<Self, HexError> {
+        let hex = s.as_bytes();
+        if !hex.len().is_multiple_of(2) {
+            return Err(HexError::OddLength);
+        }
+        let digit = |index: usize| match hex[index] {
+            b @ b'0'..=b'9' => Ok(b - b'0'),
+            b @ b'a'..=b'f' => Ok(b - b'a' + 10),
+            b @ b'A'..=b'F' => Ok(b - b'A' + 10),
+            _ => Err(HexError::InvalidDigit { index }),
+        };
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| Ok((digit(i)? << 4) | digit(i + 1)?))
+            .collect::<Result<alloc::vec::Vec<u8>, _>>()
+            .map(|bytes| Self::new(bytes.into_boxed_slice()))
+    }
+}
+
+/// An error of a buffer shorter than a length-prefixed frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    /// The bytes required for the frame, including the 4-byte length prefix.
+    pub needed: usize,
+    /// The actual length of the buffer.
+    pub available: usize,
+}
+
+impl core::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "buffer of {} bytes is too small for {} bytes",
+            self.available, self.needed
+        )
+    }
+}
+
+impl core::error::Error for BufferTooSmall {}
+
+/// Writes a little-endian `u32` length followed by `bytes`. `out` is not modified on failure.
+pub(crate) fn write_length_prefixed(
+    bytes: &[u8],
+    out: &mut MutSliceRef<u8>,
+) -> Result<usize, BufferTooSmall> {
+    let len = u32::try_from(bytes.len()).expect("length-prefixed blob is longer than u32::MAX");
+    let needed = 4 + bytes.len();
+    let out = out.as_mut();
+    if out.len() < needed {
+        return Err(BufferTooSmall {
+            needed,
+            available: out.len(),
+        });
+    }
+    out[..4].copy_from_slice(&len.to_le_bytes());
+    out[4..needed].copy_from_slice(bytes);
+    Ok(needed)
+}
+
+/// Length-prefixed framing: a little-endian `u32` length followed by the bytes.
+impl BoxedSlice<u8> {
+    /// Writes the frame into `out` and returns the bytes written. `out` is not modified on failure.
+    ///
+    /// # Panics
+    /// Panics if the length exceeds `u32::MAX`.
+    #[inline]
+    pub fn write_length_prefixed(
+        &self,
+        out: &mut MutSliceRef<u8>,
+    ) -> Result<usize, BufferTooSmall> {
+        write_length_prefixed(self, out)
+    }
+
+    /// Inverse of [`BoxedSlice::write_length_prefixed`].
+    /// Returns the copied blob and the bytes consumed from `input`, which may be followed by the next frame.
+    #[inline]
+    pub fn read_length_prefixed(input: &ByteSliceRef) -> Result<(Self, usize), BufferTooSmall> {
+        let available = input.len();
+        let too_small = |needed| BufferTooSmall { needed, available };
+        let len = input.read_u32_le(0).ok_or(too_small(4))? as usize;
+        let end = len.checked_add(4).ok_or(too_small(usize::MAX))?;
+        let bytes = input.get(4..end).ok_or(too_small(end))?;
+        Ok((Self::new(bytes.into()), end))
+    }
+}
+
+#[inline(always)]
+fn find_newline(bytes: &[u8]) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memchr(b'\n', bytes)
+    }
+    #[cfg(not(feature = "memchr"))]
+    {
+        bytes.iter().position(|&b| b == b'\n')
+    }
+}
+
+#[test]
+fn test_read_primitives() {
+    let bytes = ByteSliceRef::new(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09]);
+    assert_eq!(bytes.read_u16_le(0), Some(0x0201));
+    assert_eq!(bytes.read_u16_be(0), Some(0x0102));
+    assert_eq!(bytes.read_u32_le(1), Some(0x05040302));
+    assert_eq!(bytes.read_u32_be(5), Some(0x06070809));
+    assert_eq!(bytes.read_u64_le(1), Some(0x0908070605040302));
+    assert_eq!(bytes.read_u64_be(0), Some(0x0102030405060708));
+
+    assert_eq!(bytes.read_u16_le(8), None);
+    assert_eq!(bytes.read_u64_be(2), None);
+    assert_eq!(bytes.read_u32_le(usize::MAX), None);
+    assert_eq!(ByteSliceRef::new(&[]).read_u16_le(0), None);
+
+    // zero-initialized by C side
+    let null = SliceRef::<u8>(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    });
+    assert!(null.into_slice().is_empty());
+    assert_eq!(null.read_u32_le(0), None);
+}
+
+#[test]
+fn test_read_primitive_slices() {
+    static DATA: [u8; 9] = [0, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+    // starts at an odd address to check unaligned reads
+    let bytes = ByteSliceRef::new(&DATA[1..]);
+    let values = bytes.read_u32_slice_le().unwrap();
+    assert!(*values == [0x04030201u32, 0x08070605]);
+    let values = bytes.read_u32_slice_be().unwrap();
+    assert!(*values == [0x01020304u32, 0x05060708]);
+    let values = bytes.read_u16_slice_le().unwrap();
+    assert!(*values == [0x0201u16, 0x0403, 0x0605, 0x0807]);
+    assert!(bytes.read_u16_slice_be().unwrap()[3] == 0x0708);
+    assert!(*bytes.read_u64_slice_le().unwrap() == [0x0807060504030201u64]);
+    assert!(*bytes.read_u64_slice_be().unwrap() == [0x0102030405060708u64]);
+
+    let odd = ByteSliceRef::new(&DATA[..7]);
+    assert!(odd.read_u16_slice_le().is_none());
+    assert!(odd.read_u32_slice_be().is_none());
+    assert!(bytes.read_u64_slice_le().is_some());
+    assert!(ByteSliceRef::new(&DATA[..4]).read_u64_slice_be().is_none());
+    assert!(ByteSliceRef::new(&[])
+        .read_u32_slice_le()
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_cast_slice() {
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point {
+        x: u16,
+        y: u16,
+    }
+
+    #[repr(C, align(4))]
+    struct Aligned([u8; 12]);
+    static DATA: Aligned = Aligned([1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0]);
+    let bytes = ByteSliceRef::new(&DATA.0);
+
+    let points = unsafe { bytes.cast_slice::<Point>() }.unwrap();
+    assert_eq!(
+        &points[..],
+        &[
+            Point { x: 1, y: 2 },
+            Point { x: 3, y: 4 },
+            Point { x: 5, y: 6 }
+        ]
+    );
+    let words = unsafe { bytes.cast_slice::<u16>() }.unwrap();
+    assert_eq!(words.len(), 6);
+    assert_eq!(words[5], u16::from_le_bytes([6, 0]));
+
+    // misaligned
+    let shifted = ByteSliceRef::new(&DATA.0[1..9]);
+    assert!(unsafe { shifted.cast_slice::<u32>() }.is_none());
+    // size mismatch
+    let short = ByteSliceRef::new(&DATA.0[..10]);
+    assert!(unsafe { short.cast_slice::<u32>() }.is_none());
+    assert!(unsafe { short.cast_slice::<u16>() }.is_some());
+    assert!(unsafe { bytes.cast_slice::<()>() }.is_none());
+
+    let empty = unsafe { ByteSliceRef::default().cast_slice::<u64>() }.unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_split_first_bytes() {
+    let bytes = ByteSliceRef::new(b"header:body");
+    let (head, tail) = bytes.split_first_bytes(7).unwrap();
+    assert_eq!(&*head, b"header:");
+    assert_eq!(&*tail, b"body");
+
+    let (head, tail) = bytes.split_first_bytes(bytes.len()).unwrap();
+    assert_eq!(head.len(), bytes.len());
+    assert!(tail.is_empty());
+
+    assert!(bytes.split_first_bytes(bytes.len() + 1).is_none());
+    assert!(ByteSliceRef::new(&[]).split_first_bytes(0).is_some());
+}
+
+#[test]
+fn test_search() {
+    let bytes = ByteSliceRef::new(b"GET / HTTP/1.1\r\n\r\n");
+    assert_eq!(bytes.position(b' '), Some(3));
+    assert!(bytes.contains(b'/'));
+    assert!(!bytes.contains(0));
+    assert_eq!(bytes.find(b"\r\n\r\n"), Some(14));
+    assert_eq!(bytes.find(b"HTTP/2"), None);
+
+    // overlapping candidates
+    assert_eq!(ByteSliceRef::new(b"aaab").find(b"aab"), Some(1));
+    assert_eq!(ByteSliceRef::new(b"abababc").find(b"ababc"), Some(2));
+
+    let empty = ByteSliceRef::new(&[]);
+    assert_eq!(empty.position(0), None);
+    assert!(!empty.contains(0));
+    assert_eq!(empty.find(b"a"), None);
+    assert_eq!(empty.find(b""), Some(0));
+    assert_eq!(ByteSliceRef::new(b"ab").find(b"abc"), None);
+
+    // zero-initialized by C side
+    let null = SliceRef::<u8>(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    });
+    assert!(!null.contains(0));
+    assert_eq!(null.find(b"a"), None);
+}
+
+#[test]
+fn test_split_lines() {
+    fn lines(bytes: &'static [u8]) -> alloc::vec::Vec<&'static [u8]> {
+        ByteSliceRef::new(bytes)
+            .split_lines()
+            .map(|line| line.into_slice())
+            .collect()
+    }
+
+    assert_eq!(lines(b"a,b\nc,d\r\n\ne"), [&b"a,b"[..], b"c,d", b"", b"e"]);
+    assert_eq!(lines(b"line\n"), [b"line"]);
+    assert_eq!(lines(b"\n"), [b""]);
+    assert_eq!(lines(b"bare\r"), [b"bare\r"]);
+    assert!(lines(b"").is_empty());
+    assert!(ByteSliceRef::default().split_lines().next().is_none());
+
+    // the same as `str::lines`
+    let text = "x\r\n\ny\n\r\nz\r";
+    let expected: alloc::vec::Vec<&[u8]> = text.lines().map(str::as_bytes).collect();
+    assert_eq!(lines(text.as_bytes()), expected);
+}
+
+#[test]
+fn test_byte_cursor() {
+    // [len: u32 le][name][id: u32 le]
+    let record = ByteSliceRef::new(b"\x03\x00\x00\x00abc\x2a\x00\x00\x00");
+    let mut cursor = ByteCursor::new(&record);
+    let len = cursor.take_u32_le().unwrap();
+    assert_eq!(&*cursor.take(len as usize).unwrap(), b"abc");
+    assert_eq!(cursor.remaining(), 4);
+    assert_eq!(cursor.take_u32_le(), Some(42));
+    assert!(cursor.is_empty());
+    assert!(cursor.take(0).is_some());
+
+    // truncated input doesn't advance
+    let truncated = ByteSliceRef::new(b"\x05\x00\x00\x00ab\x01");
+    let mut cursor = ByteCursor::new(&truncated);
+    let len = cursor.take_u32_le().unwrap();
+    assert!(cursor.take(len as usize).is_none());
+    assert!(cursor.take(usize::MAX).is_none());
+    assert_eq!(cursor.remaining(), 3);
+    assert_eq!(cursor.take(2).map(|b| b.into_slice()), Some(&b"ab"[..]));
+    assert_eq!(cursor.take_u32_le(), None);
+    assert_eq!(cursor.remaining(), 1);
+
+    let empty = ByteSliceRef::default();
+    let mut cursor = ByteCursor::new(&empty);
+    assert!(cursor.is_empty());
+    assert_eq!(cursor.take_u32_le(), None);
+}
+
+#[test]
+fn test_length_prefixed() {
+    let blob = BoxedSlice::from(*b"abc");
+    let mut buffer = [0u8; 7];
+    let mut out = unsafe { MutSliceRef::new_unbound(&mut buffer) };
+    assert_eq!(blob.write_length_prefixed(&mut out), Ok(7));
+    assert_eq!(&buffer, b"\x03\0\0\0abc");
+
+    let mut short = [0xffu8; 6];
+    let mut out = unsafe { MutSliceRef::new_unbound(&mut short) };
+    let err = blob.write_length_prefixed(&mut out);
+    assert_eq!(
+        err,
+        Err(BufferTooSmall {
+            needed: 7,
+            available: 6
+        })
+    );
+    assert_eq!(short, [0xff; 6]);
+
+    let input = unsafe { ByteSliceRef::new_unbound(&buffer) };
+    let (read, consumed) = BoxedSlice::read_length_prefixed(&input).unwrap();
+    assert_eq!(&*read, b"abc");
+    assert_eq!(consumed, 7);
+
+    let truncated = unsafe { ByteSliceRef::new_unbound(&buffer[..6]) };
+    let err = BoxedSlice::read_length_prefixed(&truncated).err().unwrap();
+    assert_eq!(
+        err,
+        BufferTooSmall {
+            needed: 7,
+            available: 6
+        }
+    );
+    let err = BoxedSlice::read_length_prefixed(&ByteSliceRef::new(b"\x03"))
+        .err()
+        .unwrap();
+    assert_eq!(err.needed, 4);
+
+    let mut empty = [0u8; 4];
+    let mut out = unsafe { MutSliceRef::new_unbound(&mut empty) };
+    assert_eq!(BoxedSlice::default().write_length_prefixed(&mut out), Ok(4));
+    let (read, consumed) = BoxedSlice::read_length_prefixed(&ByteSliceRef::new(&[0; 4])).unwrap();
+    assert!(read.is_empty());
+    assert_eq!(consumed, 4);
+}
+
+#[test]
+fn test_hex() {
+    let bytes = ByteSliceRef::new(&[0x00, 0x1f, 0xa0, 0xff]);
+    let hex = bytes.to_hex();
+    assert_eq!(&*hex, "001fa0ff");
+    let decoded = BoxedSlice::from_hex(&hex).unwrap();
+    assert_eq!(&*decoded, bytes.as_ref());
+    assert_eq!(&*BoxedSlice::from_hex("001FA0FF").unwrap(), bytes.as_ref());
+
+    assert!(ByteSliceRef::default().to_hex().is_empty());
+    assert!(BoxedSlice::from_hex("").unwrap().is_empty());
+
+    assert_eq!(BoxedSlice::from_hex("abc").err(), Some(HexError::OddLength));
+    assert_eq!(
+        BoxedSlice::from_hex("0g").err(),
+        Some(HexError::InvalidDigit { index: 1 })
+    );
+    assert_eq!(
+        BoxedSlice::from_hex("é").err(),
+        Some(HexError::InvalidDigit { index: 0 })
+    );
+}
+
+#[test]
+fn test_byte_slice_starts_ends_with() {
+    let bytes = ByteSliceRef::new(b"\x7fELF\x02");
+    assert!(bytes.starts_with(b"\x7fELF"));
+    assert!(bytes.ends_with(b"\x02"));
+    assert!(bytes.starts_with(b"\x7fELF\x02") && bytes.ends_with(b"\x7fELF\x02"));
+    assert!(bytes.starts_with(b"") && bytes.ends_with(b""));
+    assert!(!bytes.starts_with(b"ELF"));
+    assert!(!bytes.ends_with(b"\x7fELF\x02\x00"));
+
+    let empty = ByteSliceRef::default();
+    assert!(empty.starts_with(b"") && empty.ends_with(b""));
+    assert!(!empty.starts_with(b"a") && !empty.ends_with(b"a"));
+}
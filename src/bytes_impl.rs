@@ -0,0 +1,78 @@
+use crate::{BoxedSlice, BoxedStr, StrRef};
+use bytes::Bytes;
+
+/// Moves the allocation into `Bytes` without copying.
+impl From<BoxedSlice<u8>> for Bytes {
+    #[inline]
+    fn from(bytes: BoxedSlice<u8>) -> Self {
+        Bytes::from(bytes.into_boxed_slice())
+    }
+}
+
+/// Copies the buffer unless `bytes` is the only owner of a buffer allocated as a `Vec<u8>`,
+/// because a `Bytes` may be shared by other handles or point into a static or a bigger buffer.
+/// Also reallocates when the buffer has spare capacity, to keep the exact-length `Box<[u8]>` layout.
+impl From<Bytes> for BoxedSlice<u8> {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        Self::new(alloc::vec::Vec::from(bytes).into_boxed_slice())
+    }
+}
+
+/// Moves the allocation into `Bytes` without copying.
+impl From<BoxedStr> for Bytes {
+    #[inline]
+    fn from(s: BoxedStr) -> Self {
+        Bytes::from(s.into_boxed_bytes())
+    }
+}
+
+/// Copies the buffer. A [`StrRef`] usually borrows memory owned by the other side,
+/// which a refcounted and cloneable `Bytes` could outlive.
+impl From<StrRef> for Bytes {
+    #[inline]
+    fn from(s: StrRef) -> Self {
+        Bytes::copy_from_slice(s.as_bytes())
+    }
+}
+
+/// Validates UTF-8. The buffer is copied in the same cases as `From<Bytes> for BoxedSlice<u8>`.
+impl TryFrom<Bytes> for BoxedStr {
+    type Error = core::str::Utf8Error;
+
+    #[inline]
+    fn try_from(bytes: Bytes) -> Result<Self, Self::Error> {
+        BoxedStr::from_boxed_slice(BoxedSlice::from(bytes))
+    }
+}
+
+#[test]
+fn test_bytes_roundtrip() {
+    let boxed = BoxedSlice::from(*b"packet");
+    let ptr = boxed.as_ptr();
+    let bytes = Bytes::from(boxed);
+    assert_eq!(bytes.as_ptr(), ptr);
+    assert_eq!(&bytes[..], b"packet");
+    let boxed = BoxedSlice::from(bytes);
+    assert_eq!(&*boxed, b"packet");
+
+    let shared = Bytes::from_static(b"shared");
+    let copied = BoxedSlice::from(shared.clone());
+    assert_eq!(&*copied, &*shared);
+    assert_ne!(copied.as_ptr(), shared.as_ptr());
+
+    let s = BoxedStr::from(alloc::boxed::Box::<str>::from("héllo"));
+    let bytes = Bytes::from(s);
+    assert_eq!(&bytes[..], "héllo".as_bytes());
+    let s = BoxedStr::try_from(bytes).unwrap();
+    assert_eq!(&*s, "héllo");
+
+    let owned = alloc::string::String::from("borrowed");
+    let s = unsafe { StrRef::new_unbound(&owned) };
+    let bytes = Bytes::from(s);
+    assert_ne!(bytes.as_ptr(), owned.as_ptr());
+    drop(owned);
+    assert_eq!(&bytes[..], b"borrowed");
+    assert!(BoxedStr::try_from(Bytes::from_static(b"a\xff")).is_err());
+    assert!(BoxedSlice::from(Bytes::new()).is_empty());
+}
@@ -1,8 +1,21 @@
+// `c_char` is `u8` without libc, so the casts for the libc `c_char` are no-ops there.
+#![cfg_attr(not(feature = "libc"), allow(clippy::unnecessary_cast))]
+
 use crate::slice::SliceInner;
 
 pub const CXX_INCLUDE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/include");
 pub const CXX_HEADER_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/include/rust_types.hxx");
 pub const CXX_HEADER_CONTENT: &str = include_str!("../include/rust_types.hxx");
+/// The C++ standard targeted by [`CXX_HEADER_CONTENT`], in the form of `-std=` flag.
+pub const CXX_HEADER_STD: &str = "c++17";
+
+/// Same as [`CXX_HEADER_PATH`] with C++20 extensions like `std::span` and `std::ranges` interop.
+pub const CXX_HEADER_PATH_CXX20: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/include/rust_types_cxx20.hxx");
+/// Same as [`CXX_HEADER_CONTENT`] with C++20 extensions like `std::span` and `std::ranges` interop.
+pub const CXX_HEADER_CONTENT_CXX20: &str = include_str!("../include/rust_types_cxx20.hxx");
+/// The C++ standard targeted by [`CXX_HEADER_CONTENT_CXX20`], in the form of `-std=` flag.
+pub const CXX_HEADER_STD_CXX20: &str = "c++20";
 
 #[allow(non_camel_case_types)]
 #[cfg(feature = "libc")]
@@ -20,7 +33,53 @@ pub type CByteSliceRef = crate::ByteSliceRef;
 
 pub type CStrRef = crate::StrRef;
 
-/// not related to [`std::ffi::CStr`] or [`std::ffi::CString`]
+#[cfg(feature = "libc")]
+impl CStrRef {
+    /// Create a new wrapper from a NUL-terminated C string after UTF-8 validation.
+    /// The terminator is not included.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid NUL-terminated string.
+    /// The returned object borrows the C buffer and must not outlive it.
+    #[inline]
+    pub unsafe fn from_cstr(ptr: *const c_char) -> Result<Self, core::str::Utf8Error> {
+        let bytes = core::ffi::CStr::from_ptr(ptr).to_bytes();
+        Ok(Self::new(core::str::from_utf8(bytes)?))
+    }
+
+    /// Create a new wrapper from a NUL-terminated C string without UTF-8 validation.
+    /// The terminator is not included.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid NUL-terminated UTF-8 string.
+    /// The returned object borrows the C buffer and must not outlive it.
+    #[inline]
+    pub unsafe fn from_cstr_unchecked(ptr: *const c_char) -> Self {
+        let bytes = core::ffi::CStr::from_ptr(ptr).to_bytes();
+        Self::new(core::str::from_utf8_unchecked(bytes))
+    }
+}
+
+/// Validates UTF-8 of a byte buffer from the other side, e.g. a protocol field. See [`CharStrRef::into_rust`] for a char buffer.
+///
+/// An empty buffer is valid and results in an empty string without reading the pointer.
+impl TryFrom<CByteSliceRef> for CStrRef {
+    type Error = core::str::Utf8Error;
+
+    #[inline]
+    fn try_from(bytes: CByteSliceRef) -> Result<Self, Self::Error> {
+        bytes.try_into_str_ref()
+    }
+}
+
+impl From<CStrRef> for CByteSliceRef {
+    #[inline(always)]
+    fn from(s: CStrRef) -> Self {
+        s.as_byte_slice_ref()
+    }
+}
+
+/// not related to [`core::ffi::CStr`] or [`alloc::ffi::CString`]
 pub type CharStrRef = crate::SliceRef<c_char>;
 
 impl CharStrRef {
@@ -29,7 +88,7 @@ impl CharStrRef {
     pub fn as_bytes(&self) -> &[u8] {
         let len = self.len();
         let ptr = self.as_ptr();
-        unsafe { std::slice::from_raw_parts(ptr as *const _, len) }
+        unsafe { core::slice::from_raw_parts(ptr as *const _, len) }
     }
     #[cfg(not(feature = "libc"))]
     #[inline(always)]
@@ -38,8 +97,8 @@ impl CharStrRef {
     }
 
     #[inline(always)]
-    pub fn to_str(&self) -> Result<&str, std::str::Utf8Error> {
-        std::str::from_utf8(self.as_bytes())
+    pub fn to_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(self.as_bytes())
     }
 
     #[inline(always)]
@@ -64,22 +123,304 @@ impl CharStrRef {
     }
 
     #[inline(always)]
-    pub fn into_rust(self) -> Result<crate::StrRef, std::str::Utf8Error> {
-        std::str::from_utf8(self.as_bytes())?;
+    pub fn into_rust(self) -> Result<crate::StrRef, core::str::Utf8Error> {
+        core::str::from_utf8(self.as_bytes())?;
         Ok(unsafe { self.into_rust_unchecked() })
     }
+
+    /// Same as [`CharStrRef::into_rust`], but the error tells where and which bytes failed to decode.
+    #[inline]
+    pub fn into_rust_verbose(self) -> Result<crate::StrRef, CharStrDecodeError> {
+        let bytes: &'static [u8] = SliceInner {
+            ptr: self.0.ptr as *mut u8,
+            len: self.0.len,
+        }
+        .checked_slice();
+        match core::str::from_utf8(bytes) {
+            Ok(_) => Ok(unsafe { self.into_rust_unchecked() }),
+            Err(error) => {
+                let start = error.valid_up_to();
+                let end = error.error_len().map_or(bytes.len(), |len| start + len);
+                Err(CharStrDecodeError {
+                    invalid_bytes: &bytes[start..end],
+                    error,
+                })
+            }
+        }
+    }
+
+    /// Create a `(ptr, len)` char view of `s` for a synchronous C call, without copying.
+    ///
+    /// The view is not NUL-terminated. For a C API taking a NUL-terminated string,
+    /// allocate a terminated copy by [`alloc::ffi::CString::new`] instead.
+    ///
+    /// # Safety
+    /// The returned object must not outlive the given str.
+    #[inline(always)]
+    pub unsafe fn from_str_unbound(s: &'_ str) -> Self {
+        Self(SliceInner {
+            ptr: s.as_ptr() as *mut c_char,
+            len: s.len(),
+        })
+    }
+
+    /// Borrows the buffer as a [`core::ffi::CStr`] when its last byte is the only NUL.
+    ///
+    /// The terminator must be included in the length.
+    /// A wrapper built by `From<&CStr>` excludes it and fails here.
+    #[cfg(feature = "libc")]
+    #[inline]
+    pub fn to_cstr(&self) -> Result<&core::ffi::CStr, core::ffi::FromBytesWithNulError> {
+        core::ffi::CStr::from_bytes_with_nul(self.as_bytes())
+    }
+}
+
+/// The wrapper covers [`core::ffi::CStr::to_bytes`], so the length excludes the NUL terminator.
+/// The terminator still follows the buffer in memory.
+#[cfg(feature = "libc")]
+impl From<&'static core::ffi::CStr> for CharStrRef {
+    #[inline(always)]
+    fn from(cstr: &'static core::ffi::CStr) -> Self {
+        let bytes = cstr.to_bytes();
+        Self(SliceInner {
+            ptr: bytes.as_ptr() as *mut c_char,
+            len: bytes.len(),
+        })
+    }
+}
+
+/// Writable counterpart of [`CharStrRef`] for a char buffer given by C to modify in place.
+///
+/// It must not be created over read-only memory like string literals of C.
+pub type CharStrMutRef = crate::MutSliceRef<c_char>;
+
+impl CharStrMutRef {
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        let chars = self.iter().as_slice();
+        unsafe { core::slice::from_raw_parts(chars.as_ptr() as *const u8, chars.len()) }
+    }
+
+    #[inline(always)]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let chars = self.iter_mut().into_slice();
+        unsafe { core::slice::from_raw_parts_mut(chars.as_mut_ptr() as *mut u8, chars.len()) }
+    }
+
+    /// Converts the buffer to ASCII upper case in place. See [`slice::make_ascii_uppercase`].
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.as_bytes_mut().make_ascii_uppercase()
+    }
+
+    /// Converts the buffer to ASCII lower case in place. See [`slice::make_ascii_lowercase`].
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.as_bytes_mut().make_ascii_lowercase()
+    }
+}
+
+/// An error of [`CharStrRef::into_rust_verbose`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CharStrDecodeError {
+    invalid_bytes: &'static [u8],
+    error: core::str::Utf8Error,
+}
+
+impl CharStrDecodeError {
+    /// Returns the byte offset of the first invalid sequence. See [`core::str::Utf8Error::valid_up_to`].
+    #[inline(always)]
+    pub fn valid_up_to(&self) -> usize {
+        self.error.valid_up_to()
+    }
+
+    /// Returns the invalid sequence, or the incomplete sequence at the end of the string.
+    ///
+    /// The bytes borrow the buffer of the decoded [`CharStrRef`], so they share its validity.
+    #[inline(always)]
+    pub fn invalid_bytes(&self) -> &[u8] {
+        self.invalid_bytes
+    }
+
+    #[inline(always)]
+    pub fn utf8_error(&self) -> core::str::Utf8Error {
+        self.error
+    }
+}
+
+impl core::fmt::Display for CharStrDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} (invalid bytes {:02x?})",
+            self.error, self.invalid_bytes
+        )
+    }
+}
+
+impl core::error::Error for CharStrDecodeError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
 }
 
 pub type CBoxedStr = crate::BoxedStr;
 
+/// Exports an `extern "C"` drop function for [`CBoxedSlice<T>`] of the given element types.
+///
+/// C has no generics, so each monomorphization needs its own exported symbol.
+/// Only `CBoxedSlice<u8>` has one out of the box, `_rust_ffi_boxed_bytes_drop`.
+/// Since cbindgen doesn't expand macros by default, declare the generated functions on the C side
+/// or enable `parse.expand`.
+///
+/// ```
+/// #[repr(C)]
+/// pub struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// ffi_types::export_boxed_slice_drop!(Point, point_slice_drop; u32, u32_slice_drop);
+///
+/// let points: ffi_types::CBoxedSlice<Point> = vec![Point { x: 0.0, y: 1.0 }].into_boxed_slice().into();
+/// unsafe { point_slice_drop(points) };
+/// ```
+#[macro_export]
+macro_rules! export_boxed_slice_drop {
+    ($($ty:ty, $name:ident);+ $(;)?) => {
+        $(
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(_slice: $crate::CBoxedSlice<$ty>) {}
+        )+
+    };
+    // exported as `$symbol` instead of the function name, used by `export_drops!`
+    ($($ty:ty, $name:ident = $symbol:expr);+ $(;)?) => {
+        $(
+            #[export_name = $symbol]
+            pub unsafe extern "C" fn $name(_slice: $crate::CBoxedSlice<$ty>) {}
+        )+
+    };
+}
+
+/// Exports an `extern "C"` drop function for [`COptionBox<T>`] of the given value types.
+///
+/// The C++ `OptionBox<T>::_drop()` needs a Rust symbol to free a box of non-trivial `T`.
+/// `COptionBox<BoxedStr>` and `COptionBox<BoxedSlice<u8>>` have ones out of the box,
+/// `_rust_ffi_option_box_str_drop` and `_rust_ffi_option_box_bytes_drop`.
+/// Specialize `OptionBox<T>::_drop()` in C++ to call the generated function.
+/// See [`export_boxed_slice_drop!`] about cbindgen.
+///
+/// ```
+/// pub struct Session {
+///     name: String,
+/// }
+///
+/// ffi_types::export_option_box_drop!(Session, session_drop);
+///
+/// let session = ffi_types::COptionBox::from_value(Session { name: "main".into() });
+/// unsafe { session_drop(session) };
+/// ```
+#[macro_export]
+macro_rules! export_option_box_drop {
+    ($($ty:ty, $name:ident);+ $(;)?) => {
+        $(
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(boxed: $crate::COptionBox<$ty>) {
+                drop(boxed.into_box());
+            }
+        )+
+    };
+    // exported as `$symbol` instead of the function name, used by `export_drops!`
+    ($($ty:ty, $name:ident = $symbol:expr);+ $(;)?) => {
+        $(
+            #[export_name = $symbol]
+            pub unsafe extern "C" fn $name(boxed: $crate::COptionBox<$ty>) {
+                drop(boxed.into_box());
+            }
+        )+
+    };
+}
+
+/// Runs [`export_boxed_slice_drop!`] and [`export_option_box_drop!`] for each listed type,
+/// with symbol names predictable from the type name instead of chosen ones. For a type `Name`:
+/// - `_rust_ffi_boxed_slice_drop_Name` for `CBoxedSlice<Name>`
+/// - `_rust_ffi_option_box_drop_Name` for `COptionBox<Name>`, which also drops `CBox<Name>` as its alias
+///
+/// Only plain type names are accepted, because the name is a part of the symbols.
+///
+/// ```
+/// #[repr(C)]
+/// pub struct Record {
+///     id: u32,
+/// }
+///
+/// ffi_types::export_drops! { Record }
+///
+/// extern "C" {
+///     fn _rust_ffi_boxed_slice_drop_Record(slice: ffi_types::CBoxedSlice<Record>);
+/// }
+///
+/// let records: ffi_types::CBoxedSlice<Record> = vec![Record { id: 1 }].into_boxed_slice().into();
+/// unsafe { _rust_ffi_boxed_slice_drop_Record(records) };
+/// ```
+#[macro_export]
+macro_rules! export_drops {
+    ($($ty:ident),+ $(,)?) => {
+        $(
+            const _: () = {
+                $crate::export_boxed_slice_drop!(
+                    $ty, boxed_slice_drop = concat!("_rust_ffi_boxed_slice_drop_", stringify!($ty))
+                );
+                $crate::export_option_box_drop!(
+                    $ty, option_box_drop = concat!("_rust_ffi_option_box_drop_", stringify!($ty))
+                );
+            };
+        )+
+    };
+}
+
 pub mod ffi {
     use super::*;
 
+    // The built-in drops are written out instead of `export_boxed_slice_drop!`/`export_option_box_drop!`,
+    // because cbindgen doesn't expand macros and the header declares these functions from this module.
+    // Other element types are left to the user crate, where the set of types is known.
     #[export_name = "_rust_ffi_boxed_str_drop"]
     pub unsafe extern "C" fn boxed_str_drop(_string: CBoxedStr) {}
 
     #[export_name = "_rust_ffi_boxed_bytes_drop"]
     pub unsafe extern "C" fn boxed_bytes_drop(_slice: CBoxedSlice<u8>) {}
+
+    #[export_name = "_rust_ffi_option_box_str_drop"]
+    pub unsafe extern "C" fn option_box_str_drop(boxed: COptionBox<crate::BoxedStr>) {
+        drop(boxed.into_box());
+    }
+
+    #[export_name = "_rust_ffi_option_box_bytes_drop"]
+    pub unsafe extern "C" fn option_box_bytes_drop(boxed: COptionBox<crate::BoxedSlice<u8>>) {
+        drop(boxed.into_box());
+    }
+
+    /// Returns the length of `slice` without relying on the struct layout of the header.
+    ///
+    /// This is a fallback for a header out of sync with the library and a symbol to link-check against.
+    #[export_name = "_rust_ffi_slice_len"]
+    pub extern "C" fn slice_len(slice: CByteSliceRef) -> usize {
+        slice.len()
+    }
+
+    /// Returns the length of `s` in bytes. See `_rust_ffi_slice_len`.
+    #[export_name = "_rust_ffi_str_len"]
+    pub extern "C" fn str_len(s: CStrRef) -> usize {
+        s.len()
+    }
+
+    /// Returns `FFI_TYPES_ABI_VERSION` of the linked library.
+    #[export_name = "_rust_ffi_abi_version"]
+    pub extern "C" fn abi_version() -> u32 {
+        crate::FFI_TYPES_ABI_VERSION
+    }
 }
 
 #[test]
@@ -112,3 +453,187 @@ fn test_empty_char_str() {
     let bytes = empty.as_bytes();
     assert_eq!(bytes, b"");
 }
+
+#[cfg(feature = "libc")]
+#[test]
+#[allow(clippy::manual_c_str_literals)] // syn 1 used by cbindgen can't parse C string literals
+fn test_str_from_cstr() {
+    let s = unsafe { CStrRef::from_cstr(b"hello\0".as_ptr() as _) }.unwrap();
+    assert_eq!(s.as_str(), "hello");
+
+    let empty = unsafe { CStrRef::from_cstr(b"\0".as_ptr() as _) }.unwrap();
+    assert_eq!(empty.as_str(), "");
+
+    let invalid = unsafe { CStrRef::from_cstr(b"a\xff\0".as_ptr() as _) };
+    assert_eq!(invalid.err().unwrap().valid_up_to(), 1);
+
+    let s = unsafe { CStrRef::from_cstr_unchecked(b"hello\0".as_ptr() as _) };
+    assert_eq!(s.as_str(), "hello");
+}
+
+#[cfg(feature = "libc")]
+#[test]
+#[allow(clippy::manual_c_str_literals)] // syn 1 used by cbindgen can't parse C string literals
+fn test_char_str_from_cstr() {
+    let cstr = core::ffi::CStr::from_bytes_with_nul(b"hello\0").unwrap();
+    let chars = CharStrRef::from(cstr);
+    assert_eq!(chars.len(), 5);
+    assert_eq!(chars.as_bytes(), b"hello");
+    assert!(chars.to_cstr().is_err());
+
+    let with_nul = CharStrRef::new(unsafe { &*(b"hello\0" as *const [u8] as *const [c_char]) });
+    assert_eq!(with_nul.to_cstr().unwrap(), cstr);
+    let inner_nul = CharStrRef::new(unsafe { &*(b"he\0lo\0" as *const [u8] as *const [c_char]) });
+    assert!(inner_nul.to_cstr().is_err());
+}
+
+#[test]
+fn test_char_str_from_str_unbound() {
+    let name = alloc::string::String::from("héllo");
+    let chars = unsafe { CharStrRef::from_str_unbound(&name) };
+    assert_eq!(chars.len(), name.len());
+    assert_eq!(chars.as_bytes(), name.as_bytes());
+    assert_eq!(chars.to_str(), Ok("héllo"));
+
+    let empty = unsafe { CharStrRef::from_str_unbound("") };
+    assert!(empty.as_bytes().is_empty());
+}
+
+#[test]
+fn test_char_str_into_rust_verbose() {
+    let bytes: &'static [u8] = b"caf\xc3\xa9 \xe2\x28\xa1 ok";
+    let chars = CharStrRef::new(unsafe { &*(bytes as *const [u8] as *const [c_char]) });
+    let Err(err) = chars.into_rust_verbose() else {
+        panic!("invalid UTF-8 must fail");
+    };
+    assert_eq!(err.valid_up_to(), 6);
+    assert_eq!(err.invalid_bytes(), b"\xe2");
+    assert_eq!(err.utf8_error(), chars.into_rust().err().unwrap());
+    assert!(alloc::format!("{}", err).contains("[e2]"));
+
+    let truncated = CharStrRef::new(unsafe { &*(b"ab\xe2\x82" as *const [u8] as *const [c_char]) });
+    let err = truncated.into_rust_verbose().err().unwrap();
+    assert_eq!(err.valid_up_to(), 2);
+    assert_eq!(err.invalid_bytes(), b"\xe2\x82");
+
+    let valid = CharStrRef::new(unsafe { &*("ok" as *const str as *const [c_char]) });
+    assert!(valid.into_rust_verbose().ok() == Some(crate::StrRef::new("ok")));
+}
+
+#[cfg(test)]
+pub struct TestRecord {
+    name: alloc::string::String,
+}
+#[cfg(test)]
+export_boxed_slice_drop!(TestRecord, _test_record_slice_drop);
+
+#[test]
+fn test_export_boxed_slice_drop() {
+    let records: CBoxedSlice<TestRecord> = alloc::vec![TestRecord {
+        name: "record".into(),
+    }]
+    .into_boxed_slice()
+    .into();
+    assert_eq!(records[0].name, "record");
+    unsafe { _test_record_slice_drop(records) };
+}
+
+#[cfg(test)]
+export_option_box_drop!(TestRecord, _test_record_option_box_drop);
+
+#[test]
+fn test_export_option_box_drop() {
+    let record = COptionBox::from_value(TestRecord {
+        name: "record".into(),
+    });
+    assert_eq!(record.as_ref().map(|r| r.name.as_str()), Some("record"));
+    unsafe { _test_record_option_box_drop(record) };
+    unsafe { _test_record_option_box_drop(COptionBox::none()) };
+
+    unsafe {
+        ffi::option_box_str_drop(COptionBox::from_value(crate::BoxedStr::new("name".into())))
+    };
+    unsafe { ffi::option_box_bytes_drop(COptionBox::none()) };
+}
+
+#[cfg(test)]
+pub struct TestDropRecord {
+    name: alloc::string::String,
+}
+#[cfg(test)]
+export_drops! { TestDropRecord, TestRecord }
+
+#[test]
+fn test_export_drops() {
+    #[allow(improper_ctypes)] // only pointers to the records are passed
+    extern "C" {
+        fn _rust_ffi_boxed_slice_drop_TestDropRecord(slice: CBoxedSlice<TestDropRecord>);
+        fn _rust_ffi_option_box_drop_TestDropRecord(boxed: COptionBox<TestDropRecord>);
+        fn _rust_ffi_option_box_drop_TestRecord(boxed: CBox<TestRecord>);
+    }
+
+    let records: CBoxedSlice<TestDropRecord> = alloc::vec![TestDropRecord {
+        name: "record".into(),
+    }]
+    .into_boxed_slice()
+    .into();
+    assert_eq!(records[0].name, "record");
+    unsafe { _rust_ffi_boxed_slice_drop_TestDropRecord(records) };
+    unsafe { _rust_ffi_option_box_drop_TestDropRecord(COptionBox::none()) };
+    unsafe {
+        _rust_ffi_option_box_drop_TestRecord(CBox::from_value(TestRecord {
+            name: "record".into(),
+        }))
+    };
+}
+
+#[test]
+fn test_char_str_mut_ascii_case() {
+    let mut buffer = *b"Hello, World!";
+    let mut chars =
+        CharStrMutRef::from(unsafe { &mut *(&mut buffer[..] as *mut [u8] as *mut [c_char]) });
+    chars.make_ascii_uppercase();
+    assert_eq!(chars.as_bytes(), b"HELLO, WORLD!");
+    chars.make_ascii_lowercase();
+    chars.as_bytes_mut()[0] = b'J';
+    assert_eq!(&buffer, b"Jello, world!");
+
+    let mut empty = CharStrMutRef::default();
+    empty.make_ascii_uppercase();
+    assert!(empty.as_bytes().is_empty());
+}
+
+#[test]
+fn test_header_abi_version() {
+    let define = alloc::format!(
+        "constexpr static const uint32_t FFI_TYPES_ABI_VERSION = {};",
+        crate::FFI_TYPES_ABI_VERSION
+    );
+    assert!(CXX_HEADER_CONTENT.contains(&define));
+    assert!(CXX_HEADER_CONTENT_CXX20.contains(&define));
+    assert_eq!(ffi::abi_version(), crate::FFI_TYPES_ABI_VERSION);
+}
+
+#[test]
+fn test_ffi_len() {
+    assert_eq!(ffi::slice_len(CByteSliceRef::new(b"abc")), 3);
+    assert_eq!(ffi::slice_len(CByteSliceRef::default()), 0);
+    assert_eq!(ffi::str_len(CStrRef::new("héllo")), 6);
+}
+
+#[test]
+fn test_c_str_ref_from_c_byte_slice_ref() {
+    let s = CStrRef::try_from(CByteSliceRef::new("héllo".as_bytes())).unwrap();
+    assert_eq!(&*s, "héllo");
+    let bytes = CByteSliceRef::from(s);
+    assert_eq!(bytes.as_ref(), "héllo".as_bytes());
+
+    let empty = CStrRef::try_from(CByteSliceRef::default()).unwrap();
+    assert!(empty.is_empty());
+    assert!(CByteSliceRef::from(empty).is_empty());
+
+    let err = CStrRef::try_from(CByteSliceRef::new(b"ab\xff"))
+        .err()
+        .unwrap();
+    assert_eq!(err.valid_up_to(), 2);
+}
@@ -1,6 +1,7 @@
 const CXX_TYPE_NAMES: &[&str] = &[
     // array
     "Array",
+    "ArrayRef",
     // simple box
     "Box",
     "OptionBox",
@@ -12,6 +13,11 @@ const CXX_TYPE_NAMES: &[&str] = &[
     // strings
     "StrRef",
     "BoxedStr",
+    // options
+    "FfiOption",
+    // results
+    "FfiResultTag",
+    "FfiResult",
 ];
 const CXX_WRAPPER_NAMES: &[&str] = &[
     // simple box
@@ -25,8 +31,71 @@ const CXX_WRAPPER_NAMES: &[&str] = &[
     "CStrRef",
     "CBoxedStr",
     "CharStrRef",
+    "CharStrMutRef",
 ];
 
+/// Types aliased by the names above, which must be emitted together when monomorphized.
+const CXX_ALIAS_TARGETS: &[(&str, &str)] = &[
+    ("ByteSliceRef", "SliceRef"),
+    ("CBox", "COptionBox"),
+    ("COptionBox", "OptionBox"),
+    ("CSliceRef", "SliceRef"),
+    ("CByteSliceRef", "ByteSliceRef"),
+    ("CBoxedSlice", "BoxedSlice"),
+    ("CStrRef", "StrRef"),
+    ("CBoxedStr", "BoxedStr"),
+    ("CharStrRef", "SliceRef"),
+    ("CharStrMutRef", "MutSliceRef"),
+];
+
+/// Boilerplate for `cbindgen::Config::after_includes`, the same as the header of this crate.
+pub const CXX_AFTER_INCLUDES: &str =
+    "\n//! This header is intended to be included in rust_types.hh file.\n";
+
+/// Returns a C++ `cbindgen::Config` using the types of this crate, mapped to `namespace`.
+///
+/// The config includes `rust_types.hxx` with `pragma_once` and `cpp_compat`, and the excludes and renames
+/// of [`with_cxx_ffi_types_with_namespace`] are already applied to `export`.
+/// `namespace` is the namespace of the types, not of the generated items; set `Config::namespace` for them.
+/// Set `Config::after_includes` to [`CXX_AFTER_INCLUDES`] for the boilerplate of this crate.
+pub fn cbindgen_config(namespace: &str) -> cbindgen::Config {
+    let mut config = cbindgen::Config::default();
+    config.language = cbindgen::Language::Cxx;
+    config.cpp_compat = true;
+    config.pragma_once = true;
+    config.includes = alloc::vec!["rust_types.hxx".into()];
+    for name in CXX_TYPE_NAMES.iter().chain(CXX_WRAPPER_NAMES) {
+        config
+            .export
+            .exclude
+            .push(alloc::string::String::from(*name));
+        config
+            .export
+            .rename
+            .insert((*name).into(), alloc::format!("{}::{}", namespace, name));
+    }
+    config
+}
+
+/// Writes the C++ header, the same as [`CXX_HEADER_CONTENT`](crate::CXX_HEADER_CONTENT), with `namespace`
+/// in place of `ffi_types`.
+///
+/// Use the same `namespace` for [`cbindgen_config`] or [`with_cxx_ffi_types_with_namespace`].
+/// The header is still compiled by [`CXX_HEADER_STD`](crate::CXX_HEADER_STD).
+#[cfg(feature = "std")]
+pub fn write_cxx_header<W: std::io::Write>(w: &mut W, namespace: &str) -> std::io::Result<()> {
+    let header = crate::CXX_HEADER_CONTENT
+        .replace(
+            "namespace ffi_types",
+            &alloc::format!("namespace {}", namespace),
+        )
+        .replace("ffi_types::", &alloc::format!("{}::", namespace));
+    w.write_all(header.as_bytes())
+}
+
+/// Adds the excludes and renames for the types of this crate to `builder`.
+///
+/// [`cbindgen_config`] gives a whole config with the same mapping.
 #[must_use]
 pub fn with_cxx_ffi_types(builder: cbindgen::Builder) -> cbindgen::Builder {
     with_cxx_ffi_types_with_namespace(builder, "ffi_types")
@@ -34,18 +103,170 @@ pub fn with_cxx_ffi_types(builder: cbindgen::Builder) -> cbindgen::Builder {
 
 #[must_use]
 pub fn with_cxx_ffi_types_with_namespace(
+    builder: cbindgen::Builder,
+    namespace: &str,
+) -> cbindgen::Builder {
+    with_cxx_ffi_types_monomorphized_with_namespace(builder, namespace, &[])
+}
+
+/// Same as [`with_cxx_ffi_types`], but lets cbindgen emit concrete C structs for `types`.
+///
+/// See [`with_cxx_ffi_types_monomorphized_with_namespace`].
+#[must_use]
+pub fn with_cxx_ffi_types_monomorphized(
+    builder: cbindgen::Builder,
+    types: &[&str],
+) -> cbindgen::Builder {
+    with_cxx_ffi_types_monomorphized_with_namespace(builder, "ffi_types", types)
+}
+
+/// Same as [`with_cxx_ffi_types_with_namespace`], but lets cbindgen emit concrete C structs for `types`.
+///
+/// The listed types and the types they alias (e.g. `BoxedStr` for `CBoxedStr`) are not excluded.
+/// Instead they are prefixed as `{namespace}_{name}`, and their `u8` monomorphizations as `{namespace}_{name}_u8`.
+/// e.g. `ByteSliceRef` becomes `ffi_types_ByteSliceRef` and `CBoxedSlice<u8>` becomes `ffi_types_CBoxedSlice_u8`.
+/// The other types are mapped to the C++ header as usual.
+///
+/// This is intended for `cbindgen::Language::C` output, where generic types are always monomorphized.
+///
+/// cbindgen must parse this crate to find the struct definitions, e.g. by `parse.parse_deps` and `parse.include`.
+#[must_use]
+pub fn with_cxx_ffi_types_monomorphized_with_namespace(
     mut builder: cbindgen::Builder,
     namespace: &str,
+    types: &[&str],
 ) -> cbindgen::Builder {
+    let mut monomorphized: alloc::vec::Vec<&str> = types.to_vec();
+    let mut index = 0;
+    while let Some(name) = monomorphized.get(index) {
+        if let Some((_, target)) = CXX_ALIAS_TARGETS.iter().find(|(alias, _)| alias == name) {
+            if !monomorphized.contains(target) {
+                monomorphized.push(target);
+            }
+        }
+        index += 1;
+    }
+
     // builder = builder.with_sys_include("rust_types.h");
-    for name in CXX_TYPE_NAMES {
+    for name in CXX_TYPE_NAMES.iter().chain(CXX_WRAPPER_NAMES) {
+        if monomorphized.contains(name) {
+            continue;
+        }
         builder = builder.exclude_item(name);
-        builder = builder.rename_item(name, &format!("{}::{}", namespace, name).as_str());
+        builder = builder.rename_item(name, &alloc::format!("{}::{}", namespace, name).as_str());
     }
-    for name in CXX_WRAPPER_NAMES {
-        builder = builder.exclude_item(name);
-        builder = builder.rename_item(name, &format!("{}::{}", namespace, name).as_str());
+    if !monomorphized.is_empty() {
+        monomorphized.push("SliceInner");
+    }
+    for name in monomorphized {
+        builder = builder.rename_item(name, alloc::format!("{}_{}", namespace, name).as_str());
+        builder = builder.rename_item(
+            alloc::format!("{}_u8", name),
+            alloc::format!("{}_{}_u8", namespace, name),
+        );
     }
 
     builder
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_monomorphized() {
+    let crate_dir = env!("CARGO_MANIFEST_DIR");
+    // unique per process so that concurrent test runs don't overwrite each other
+    let api_path = std::env::temp_dir().join(alloc::format!(
+        "ffi_types_test_monomorphized_{}.rs",
+        std::process::id()
+    ));
+    std::fs::write(
+        &api_path,
+        r#"
+        #[no_mangle]
+        pub extern "C" fn api(a: ByteSliceRef, b: CBoxedSlice<u8>) -> CBoxedStr {
+            todo!()
+        }
+        "#,
+    )
+    .unwrap();
+
+    let builder = cbindgen::Builder::new()
+        .with_language(cbindgen::Language::C)
+        .with_src(&api_path)
+        .with_src(alloc::format!("{}/src/slice.rs", crate_dir))
+        .with_src(alloc::format!("{}/src/str.rs", crate_dir))
+        .with_src(alloc::format!("{}/src/c.rs", crate_dir));
+    let builder =
+        with_cxx_ffi_types_monomorphized(builder, &["ByteSliceRef", "CBoxedSlice", "CBoxedStr"]);
+    let bindings = builder.generate();
+    std::fs::remove_file(&api_path).unwrap();
+    let mut header = alloc::vec::Vec::new();
+    bindings.unwrap().write(&mut header);
+    let header = alloc::string::String::from_utf8(header).unwrap();
+
+    assert!(header.contains("typedef struct ffi_types_SliceInner_u8 {"));
+    assert!(header.contains("typedef ffi_types_SliceRef_u8 ffi_types_ByteSliceRef;"));
+    assert!(header.contains(
+        "ffi_types_CBoxedStr api(ffi_types_ByteSliceRef a, ffi_types_CBoxedSlice_u8 b);"
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_cbindgen_config() {
+    // unique per process so that concurrent test runs don't overwrite each other
+    let api_path = std::env::temp_dir().join(alloc::format!(
+        "ffi_types_test_cbindgen_config_{}.rs",
+        std::process::id()
+    ));
+    std::fs::write(
+        &api_path,
+        r#"
+        #[no_mangle]
+        pub extern "C" fn api(a: ByteSliceRef, b: CBoxedSlice<u8>) -> CBoxedStr {
+            todo!()
+        }
+
+        #[no_mangle]
+        pub extern "C" fn key(k: ArrayRef<u8, 16>) {}
+        "#,
+    )
+    .unwrap();
+
+    let mut config = cbindgen_config("rust");
+    config.namespace = Some("app".into());
+    config.after_includes = Some(CXX_AFTER_INCLUDES.into());
+    let bindings = cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(&api_path)
+        .generate();
+    std::fs::remove_file(&api_path).unwrap();
+    let bindings = bindings.unwrap();
+    let mut header = alloc::vec::Vec::new();
+    bindings.write(&mut header);
+    let header = alloc::string::String::from_utf8(header).unwrap();
+
+    assert!(header.contains("#pragma once"));
+    assert!(header.contains("#include \"rust_types.hxx\""));
+    assert!(header.contains(CXX_AFTER_INCLUDES));
+    assert!(header.contains("namespace app {"));
+    assert!(
+        header.contains("rust::CBoxedStr api(rust::ByteSliceRef a, rust::CBoxedSlice<uint8_t> b);")
+    );
+    assert!(header.contains("void key(rust::ArrayRef<uint8_t, 16> k);"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_cxx_header() {
+    let mut header = alloc::vec::Vec::new();
+    write_cxx_header(&mut header, "ffi_types").unwrap();
+    assert_eq!(header, crate::CXX_HEADER_CONTENT.as_bytes());
+
+    let mut header = alloc::vec::Vec::new();
+    write_cxx_header(&mut header, "app::ffi").unwrap();
+    let header = alloc::string::String::from_utf8(header).unwrap();
+    assert!(header.contains("namespace app::ffi {"));
+    assert!(header.contains("app::ffi::_rust_ffi_abi_version()"));
+    assert!(!header.contains("ffi_types::"));
+    assert!(!header.contains("namespace ffi_types"));
+}
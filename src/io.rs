@@ -0,0 +1,152 @@
+use crate::{ByteSliceRef, MutSliceRef};
+
+/// [`std::io::Write`] into a buffer given as [`MutSliceRef<u8>`], e.g. an output buffer of the caller.
+///
+/// Writing behaves like `&mut [u8]`: a write is cut at the end of the buffer and a full buffer writes 0 bytes,
+/// so `write_all` and `write!` fail with [`std::io::ErrorKind::WriteZero`].
+pub struct MutSliceCursor {
+    buffer: MutSliceRef<u8>,
+    position: usize,
+}
+
+impl MutSliceCursor {
+    #[inline(always)]
+    pub fn new(buffer: MutSliceRef<u8>) -> Self {
+        Self {
+            buffer,
+            position: 0,
+        }
+    }
+
+    /// Returns the number of bytes written so far.
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() - self.position
+    }
+
+    /// Returns the written part of the buffer.
+    #[inline(always)]
+    pub fn written(&self) -> &[u8] {
+        &self.buffer[..self.position]
+    }
+
+    /// Gives back the buffer. The written length is lost; take [`MutSliceCursor::position`] before.
+    #[inline(always)]
+    pub fn into_inner(self) -> MutSliceRef<u8> {
+        self.buffer
+    }
+}
+
+impl std::io::Write for MutSliceCursor {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(self.remaining());
+        let end = self.position + len;
+        self.buffer[self.position..end].copy_from_slice(&buf[..len]);
+        self.position = end;
+        Ok(len)
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// [`std::io::Read`] from a buffer given as [`ByteSliceRef`], e.g. to feed it to a `Read`-based decoder.
+///
+/// Reading at the end of the buffer returns `Ok(0)` for EOF.
+pub struct SliceReader {
+    buffer: ByteSliceRef,
+    position: usize,
+}
+
+impl SliceReader {
+    #[inline(always)]
+    pub fn new(buffer: ByteSliceRef) -> Self {
+        Self {
+            buffer,
+            position: 0,
+        }
+    }
+
+    /// Returns the number of bytes read so far.
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the unread part of the buffer.
+    #[inline(always)]
+    pub fn remaining(&self) -> &[u8] {
+        &self.buffer[self.position..]
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> ByteSliceRef {
+        self.buffer
+    }
+}
+
+impl std::io::Read for SliceReader {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(self.remaining().len());
+        buf[..len].copy_from_slice(&self.remaining()[..len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+#[test]
+fn test_mut_slice_cursor_write() {
+    use std::io::Write;
+
+    let mut buffer = [0u8; 8];
+    let mut cursor = MutSliceCursor::new(unsafe { MutSliceRef::new_unbound(&mut buffer) });
+    write!(cursor, "id={}", 42).unwrap();
+    assert_eq!(cursor.written(), b"id=42");
+    assert_eq!(cursor.remaining(), 3);
+
+    assert_eq!(cursor.write(b"abcdef").unwrap(), 3);
+    assert_eq!(cursor.write(b"x").unwrap(), 0);
+    let err = cursor.write_all(b"x").unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+    assert_eq!(cursor.position(), 8);
+    assert_eq!(&buffer, b"id=42abc");
+
+    let mut empty = MutSliceCursor::new(MutSliceRef::default());
+    assert_eq!(empty.write(b"a").unwrap(), 0);
+    assert!(empty.written().is_empty());
+}
+
+#[test]
+fn test_slice_reader_read() {
+    use std::io::Read;
+
+    let mut reader = SliceReader::new(ByteSliceRef::new(b"hello, world"));
+    let mut buf = [0u8; 5];
+    assert_eq!(reader.read(&mut buf).unwrap(), 5);
+    assert_eq!(&buf, b"hello");
+    assert_eq!(reader.read(&mut buf[..2]).unwrap(), 2);
+    assert_eq!(&buf[..2], b", ");
+    assert_eq!(reader.position(), 7);
+
+    let mut rest = alloc::string::String::new();
+    reader.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, "world");
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    assert!(reader.remaining().is_empty());
+
+    let mut empty = SliceReader::new(ByteSliceRef::default());
+    assert_eq!(empty.read(&mut buf).unwrap(), 0);
+    assert_eq!(
+        empty.read_exact(&mut buf).unwrap_err().kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+}
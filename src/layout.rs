@@ -0,0 +1,158 @@
+//! Machine-readable `#[repr(C)]` layouts for binding generators of other languages.
+
+use crate::slice::SliceInner;
+use core::mem::{align_of, offset_of, size_of};
+
+/// Returns a JSON description of the C ABI layout of the wrapper types.
+///
+/// Every size and offset is computed at compile time for the target, e.g. for C# `StructLayout` or P/Invoke.
+/// The shape is `{"pointer_size":8,"types":[{"name":"SliceRef","size":16,"align":8,"fields":[{"name":"ptr","offset":0,"size":8},{"name":"len","offset":8,"size":8}]}]}`.
+/// Generic types are described with a sized `T`; the layout doesn't depend on it.
+#[inline(always)]
+pub fn ffi_layout_descriptor() -> &'static str {
+    // SAFETY: the writer only takes ASCII
+    unsafe { core::str::from_utf8_unchecked(DESCRIPTOR.as_bytes()) }
+}
+
+const SLICE_FIELDS: &[(&str, usize, usize)] = &[
+    ("ptr", offset_of!(SliceInner<u8>, ptr), size_of::<*mut u8>()),
+    ("len", offset_of!(SliceInner<u8>, len), size_of::<usize>()),
+];
+
+const OPTION_BOX_FIELDS: &[(&str, usize, usize)] = &[(
+    "ptr",
+    offset_of!(crate::OptionBox<u8>, ptr),
+    size_of::<*mut u8>(),
+)];
+
+/// (name, size, align, fields)
+type TypeLayout = (
+    &'static str,
+    usize,
+    usize,
+    &'static [(&'static str, usize, usize)],
+);
+
+const TYPES: &[TypeLayout] = &[
+    layout::<crate::SliceRef<u8>>("SliceRef", SLICE_FIELDS),
+    layout::<crate::MutSliceRef<u8>>("MutSliceRef", SLICE_FIELDS),
+    layout::<crate::BoxedSlice<u8>>("BoxedSlice", SLICE_FIELDS),
+    layout::<crate::StrRef>("StrRef", SLICE_FIELDS),
+    layout::<crate::BoxedStr>("BoxedStr", SLICE_FIELDS),
+    layout::<crate::OptionBox<u8>>("OptionBox", OPTION_BOX_FIELDS),
+];
+
+const fn layout<T>(
+    name: &'static str,
+    fields: &'static [(&'static str, usize, usize)],
+) -> TypeLayout {
+    (name, size_of::<T>(), align_of::<T>(), fields)
+}
+
+static DESCRIPTOR: Writer = describe();
+
+const fn describe() -> Writer {
+    let mut w = Writer::new()
+        .str("{\"pointer_size\":")
+        .usize(size_of::<*const u8>())
+        .str(",\"types\":[");
+    let mut i = 0;
+    while i < TYPES.len() {
+        let (name, size, align, fields) = TYPES[i];
+        if i > 0 {
+            w = w.str(",");
+        }
+        w = w
+            .str("{\"name\":\"")
+            .str(name)
+            .str("\",\"size\":")
+            .usize(size)
+            .str(",\"align\":")
+            .usize(align)
+            .str(",\"fields\":[");
+        let mut j = 0;
+        while j < fields.len() {
+            let (name, offset, size) = fields[j];
+            if j > 0 {
+                w = w.str(",");
+            }
+            w = w
+                .str("{\"name\":\"")
+                .str(name)
+                .str("\",\"offset\":")
+                .usize(offset)
+                .str(",\"size\":")
+                .usize(size)
+                .str("}");
+            j += 1;
+        }
+        w = w.str("]}");
+        i += 1;
+    }
+    w.str("]}")
+}
+
+/// Fixed buffer to build the descriptor in const context.
+struct Writer {
+    buf: [u8; 1024],
+    len: usize,
+}
+
+impl Writer {
+    const fn new() -> Self {
+        Self {
+            buf: [0; 1024],
+            len: 0,
+        }
+    }
+
+    const fn as_bytes(&self) -> &[u8] {
+        self.buf.split_at(self.len).0
+    }
+
+    const fn str(mut self, s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            self.buf[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    const fn usize(mut self, mut n: usize) -> Self {
+        let mut digits = [0u8; 20];
+        let mut count = 0;
+        loop {
+            digits[count] = b'0' + (n % 10) as u8;
+            count += 1;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        while count > 0 {
+            count -= 1;
+            self.buf[self.len] = digits[count];
+            self.len += 1;
+        }
+        self
+    }
+}
+
+#[test]
+fn test_ffi_layout_descriptor() {
+    let descriptor: serde_json::Value = serde_json::from_str(ffi_layout_descriptor()).unwrap();
+    assert_eq!(descriptor["pointer_size"], size_of::<usize>());
+
+    let types = descriptor["types"].as_array().unwrap();
+    let slice_ref = types.iter().find(|t| t["name"] == "SliceRef").unwrap();
+    assert_eq!(slice_ref["size"], size_of::<&[u8]>());
+    assert_eq!(slice_ref["fields"][0]["name"], "ptr");
+    assert_eq!(slice_ref["fields"][1]["offset"], size_of::<usize>());
+
+    let option_box = types.iter().find(|t| t["name"] == "OptionBox").unwrap();
+    assert_eq!(option_box["size"], size_of::<usize>());
+    assert_eq!(option_box["fields"].as_array().unwrap().len(), 1);
+}
@@ -0,0 +1,35 @@
+//! Field offsets and alignments of the `#[repr(C)]` types, which the size assertions alone don't cover.
+
+use crate::slice::SliceInner;
+use crate::{Box, BoxedSlice, BoxedStr, MutSliceRef, OptionBox, SliceRef, StrRef};
+use core::mem::{align_of, offset_of, size_of};
+
+#[test]
+fn test_slice_inner_layout() {
+    // the same order as the raw parts of `&[T]`
+    assert_eq!(offset_of!(SliceInner<u8>, ptr), 0);
+    assert_eq!(offset_of!(SliceInner<u8>, len), size_of::<usize>());
+    assert_eq!(offset_of!(SliceInner<u64>, len), size_of::<usize>());
+    assert_eq!(align_of::<SliceInner<u8>>(), align_of::<&[u8]>());
+
+    let slice: &[u16] = &[1, 2, 3];
+    let inner = SliceInner::from_slice(slice);
+    let words: [usize; 2] = unsafe { core::mem::transmute(slice) };
+    assert_eq!(words, [inner.ptr as usize, inner.len]);
+}
+
+#[test]
+fn test_wrapper_alignment() {
+    assert_eq!(align_of::<SliceRef<u64>>(), align_of::<&[u64]>());
+    assert_eq!(align_of::<MutSliceRef<u64>>(), align_of::<&mut [u64]>());
+    assert_eq!(align_of::<BoxedSlice<u64>>(), align_of::<Box<[u64]>>());
+    assert_eq!(align_of::<StrRef>(), align_of::<&str>());
+    assert_eq!(align_of::<BoxedStr>(), align_of::<Box<str>>());
+}
+
+#[test]
+fn test_option_box_layout() {
+    assert_eq!(offset_of!(OptionBox<u64>, ptr), 0);
+    assert_eq!(align_of::<OptionBox<u64>>(), align_of::<Option<Box<u64>>>());
+    assert_eq!(align_of::<OptionBox<u8>>(), align_of::<*mut u8>());
+}
@@ -1,26 +1,79 @@
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod array;
+#[cfg(target_has_atomic = "ptr")]
+mod atomic;
 mod boxed;
-#[cfg(feature = "cxx")]
+mod byte_slice;
+#[cfg(feature = "bytes")]
+mod bytes_impl;
+#[cfg(feature = "c-types")]
 mod c;
-#[cfg(feature = "cxx")]
+#[cfg(feature = "cbindgen")]
 pub mod cbindgen;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "interop-descriptor")]
+mod layout;
+#[cfg(test)]
+mod layout_tests;
+mod option;
+mod result;
+mod secret;
+#[cfg(feature = "serde")]
+mod serde_impl;
 mod slice;
 mod str;
+#[cfg(feature = "vec")]
+mod vec;
 
-pub use boxed::{Box, OptionBox};
-#[cfg(feature = "cxx")]
+pub use array::ArrayRef;
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::AtomicOptionBox;
+pub use boxed::{box_from_raw, box_into_raw, Box, NonNullBox, OptionBox};
+pub use byte_slice::{BufferTooSmall, ByteCursor, HexError};
+#[cfg(feature = "c-types")]
 pub use c::{
-    CBox, CBoxedSlice, CBoxedStr, CByteSliceRef, COptionBox, CSliceRef, CStrRef, CharStrRef,
-    CXX_HEADER_CONTENT, CXX_HEADER_PATH, CXX_INCLUDE_PATH,
+    CBox, CBoxedSlice, CBoxedStr, CByteSliceRef, COptionBox, CSliceRef, CStrRef,
+    CharStrDecodeError, CharStrMutRef, CharStrRef, CXX_HEADER_CONTENT, CXX_HEADER_CONTENT_CXX20,
+    CXX_HEADER_PATH, CXX_HEADER_PATH_CXX20, CXX_HEADER_STD, CXX_HEADER_STD_CXX20, CXX_INCLUDE_PATH,
+};
+#[cfg(feature = "std")]
+pub use io::{MutSliceCursor, SliceReader};
+#[cfg(feature = "interop-descriptor")]
+pub use layout::ffi_layout_descriptor;
+pub use option::FfiOption;
+pub use result::{FfiResult, FfiResultTag};
+pub use secret::SecretBoxedSlice;
+pub use slice::{
+    BoxedSlice, ByteSliceRef, IndexError, MutSliceRef, RawSlice, SliceRef, SliceRefLt,
 };
-pub use slice::{BoxedSlice, ByteSliceRef, MutSliceRef, SliceRef};
-pub use str::{BoxedStr, StrRef};
+pub use str::{BoxedStr, BoxedStrBuilder, StrRef, StrRefLt};
+#[cfg(feature = "vec")]
+pub use vec::FfiVec;
+
+/// Re-exports the core types to write bindings with `use ffi_types::prelude::*;`.
+pub mod prelude {
+    pub use crate::{Box, BoxedSlice, BoxedStr, MutSliceRef, OptionBox, SliceRef, StrRef};
+}
+
+/// Version of the `#[repr(C)]` layouts of the types in this crate.
+///
+/// This is bumped whenever any layout changes. The C++ header has the same constant generated from this.
+/// Compare it with `_rust_ffi_abi_version()` at startup to catch a header and a library from different versions,
+/// e.g. `assert(ffi_types::abi_version_matches())` in C++.
+pub const FFI_TYPES_ABI_VERSION: u32 = 2;
 
 pub type Array<T, const N: usize> = [T; N];
 
 unsafe fn into_static<T: ?Sized>(value: &T) -> &'static T {
-    std::mem::transmute(value)
+    core::mem::transmute(value)
 }
 
 unsafe fn into_static_mut<T: ?Sized>(value: &mut T) -> &'static mut T {
-    std::mem::transmute(value)
+    core::mem::transmute(value)
 }
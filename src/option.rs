@@ -0,0 +1,141 @@
+use core::mem::MaybeUninit;
+
+/// C ABI compatible wrapper for `Option<T>` of small `Copy` values like `u32` or `bool`.
+///
+/// `Option<T>` has no guaranteed layout except for pointer-like types; use [`crate::OptionBox`] for boxes.
+/// The layout is `{ has_value: u8, value: T }` by `#[repr(C)]`.
+/// `value` is placed at the next multiple of `align_of::<T>()`, so there are `align_of::<T>() - 1` padding bytes
+/// after `has_value`, and the total size is rounded up to the alignment of `T`.
+/// e.g. `FfiOption<u32>` is 8 bytes with `value` at offset 4, and `FfiOption<bool>` is 2 bytes.
+///
+/// `has_value` is 1 for a value and 0 for none; `value` is uninitialized for none and must not be read.
+#[repr(C)]
+pub struct FfiOption<T: Copy> {
+    has_value: u8,
+    value: MaybeUninit<T>,
+}
+static_assertions::assert_eq_size!(FfiOption<u32>, [u32; 2]);
+static_assertions::assert_eq_size!(FfiOption<bool>, [u8; 2]);
+static_assertions::assert_eq_size!(FfiOption<u64>, [u64; 2]);
+
+impl<T: Copy> FfiOption<T> {
+    #[inline(always)]
+    pub const fn some(value: T) -> Self {
+        Self {
+            has_value: 1,
+            value: MaybeUninit::new(value),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn none() -> Self {
+        Self {
+            has_value: 0,
+            value: MaybeUninit::uninit(),
+        }
+    }
+
+    #[inline]
+    pub const fn from_option(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Self::some(value),
+            None => Self::none(),
+        }
+    }
+
+    /// Inverse of [`FfiOption::from_option`].
+    ///
+    /// Any non-zero `has_value` from C is taken as a value.
+    #[inline]
+    pub const fn into_option(self) -> Option<T> {
+        if self.is_some() {
+            // SAFETY: `value` is initialized when `has_value` is set
+            Some(unsafe { self.value.assume_init() })
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub const fn is_some(&self) -> bool {
+        self.has_value != 0
+    }
+
+    #[inline(always)]
+    pub const fn is_none(&self) -> bool {
+        self.has_value == 0
+    }
+}
+
+impl<T: Copy> Clone for FfiOption<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Copy> Copy for FfiOption<T> {}
+
+impl<T: Copy> Default for FfiOption<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<T: Copy + PartialEq> PartialEq for FfiOption<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_option() == other.into_option()
+    }
+}
+
+impl<T: Copy + Eq> Eq for FfiOption<T> {}
+
+impl<T: Copy> From<Option<T>> for FfiOption<T> {
+    #[inline(always)]
+    fn from(value: Option<T>) -> Self {
+        Self::from_option(value)
+    }
+}
+
+impl<T: Copy> From<FfiOption<T>> for Option<T> {
+    #[inline(always)]
+    fn from(value: FfiOption<T>) -> Self {
+        value.into_option()
+    }
+}
+
+#[test]
+fn test_ffi_option_u32() {
+    assert_eq!(FfiOption::some(42u32).into_option(), Some(42));
+    assert_eq!(FfiOption::<u32>::none().into_option(), None);
+    assert_eq!(Option::from(FfiOption::from(Some(7u32))), Some(7));
+    assert!(FfiOption::<u32>::default().is_none());
+    assert_eq!(core::mem::offset_of!(FfiOption<u32>, value), 4);
+}
+
+#[test]
+fn test_ffi_option_bool() {
+    assert_eq!(FfiOption::some(false).into_option(), Some(false));
+    assert_eq!(FfiOption::some(true).into_option(), Some(true));
+    assert!(FfiOption::<bool>::none().is_none());
+    assert!(FfiOption::some(false) != FfiOption::none());
+    assert_eq!(core::mem::offset_of!(FfiOption<bool>, value), 1);
+}
+
+#[test]
+fn test_ffi_option_struct() {
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point {
+        x: i16,
+        y: i16,
+    }
+
+    let point = FfiOption::some(Point { x: 1, y: -1 });
+    assert!(point.is_some());
+    assert_eq!(point.into_option(), Some(Point { x: 1, y: -1 }));
+    assert_eq!(core::mem::size_of::<FfiOption<Point>>(), 6);
+    assert_eq!(core::mem::offset_of!(FfiOption<Point>, value), 2);
+}
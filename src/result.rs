@@ -0,0 +1,246 @@
+use crate::OptionBox;
+use alloc::boxed::Box;
+use core::mem::ManuallyDrop;
+
+/// Discriminant of [`FfiResult`].
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FfiResultTag {
+    Ok = 0,
+    Err = 1,
+}
+
+impl FfiResultTag {
+    /// Returns `None` for a byte which is not a valid tag.
+    #[inline(always)]
+    pub const fn from_u8(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::Ok),
+            1 => Some(Self::Err),
+            _ => None,
+        }
+    }
+}
+
+/// C ABI compatible wrapper for `Result<T, E>`.
+///
+/// The layout is `{ tag: u8, value: union { ok: *mut T, err: *mut E } }`.
+/// `tag` tells which field of the union is active and the active pointer is always a valid Rust box.
+/// The tag is stored as a plain `u8` because the other side may write any byte.
+/// A byte other than [`FfiResultTag`] values is neither ok nor err; unwrapping it panics and dropping it leaks.
+/// Both variants are boxed to keep the layout independent from `T` and `E`.
+#[repr(C)]
+pub struct FfiResult<T, E> {
+    tag: u8,
+    value: FfiResultValue<T, E>,
+}
+static_assertions::assert_eq_size!(FfiResult<u8, u8>, [usize; 2]);
+static_assertions::assert_impl_all!(FfiResult<u8, u8>: Send, Sync);
+static_assertions::assert_not_impl_any!(FfiResult<u8, alloc::rc::Rc<u8>>: Send, Sync);
+
+#[repr(C)]
+union FfiResultValue<T, E> {
+    ok: ManuallyDrop<OptionBox<T>>,
+    err: ManuallyDrop<OptionBox<E>>,
+}
+static_assertions::assert_eq_size!(FfiResultValue<u8, u8>, *const u8);
+
+impl<T, E> FfiResult<T, E> {
+    #[inline]
+    pub fn from_result(result: Result<T, E>) -> Self {
+        match result {
+            Ok(value) => Self {
+                tag: FfiResultTag::Ok as u8,
+                value: FfiResultValue {
+                    ok: ManuallyDrop::new(OptionBox::from_value(value)),
+                },
+            },
+            Err(error) => Self {
+                tag: FfiResultTag::Err as u8,
+                value: FfiResultValue {
+                    err: ManuallyDrop::new(OptionBox::from_value(error)),
+                },
+            },
+        }
+    }
+
+    /// Inverse of [`FfiResult::from_result`].
+    ///
+    /// # Panics
+    /// Panics if the tag is invalid.
+    #[inline]
+    pub fn into_result(self) -> Result<T, E> {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `tag` is matching to the initialized field and the box is a valid Rust box
+        unsafe {
+            match this.tag() {
+                FfiResultTag::Ok => Ok(*Box::from_raw(this.value.ok.ptr)),
+                FfiResultTag::Err => Err(*Box::from_raw(this.value.err.ptr)),
+            }
+        }
+    }
+
+    /// # Panics
+    /// Panics if the tag is invalid. See [`FfiResult::raw_tag`] to check it.
+    #[inline(always)]
+    pub const fn tag(&self) -> FfiResultTag {
+        match FfiResultTag::from_u8(self.tag) {
+            Some(tag) => tag,
+            None => panic!("invalid FfiResult tag"),
+        }
+    }
+
+    /// Returns the stored tag byte, which may be invalid when written by the other side.
+    #[inline(always)]
+    pub const fn raw_tag(&self) -> u8 {
+        self.tag
+    }
+
+    /// Returns `false` for an invalid tag.
+    #[inline(always)]
+    pub const fn is_ok(&self) -> bool {
+        self.tag == FfiResultTag::Ok as u8
+    }
+
+    /// Returns `false` for an invalid tag.
+    #[inline(always)]
+    pub const fn is_err(&self) -> bool {
+        self.tag == FfiResultTag::Err as u8
+    }
+
+    /// # Panics
+    /// Panics if the tag is invalid.
+    #[inline]
+    pub fn as_ref(&self) -> Result<&T, &E> {
+        // SAFETY: `tag` is matching to the initialized field and the box is a valid Rust box
+        unsafe {
+            match self.tag() {
+                FfiResultTag::Ok => Ok(&*self.value.ok.ptr),
+                FfiResultTag::Err => Err(&*self.value.err.ptr),
+            }
+        }
+    }
+}
+
+impl<T, E> Drop for FfiResult<T, E> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: `tag` is matching to the initialized field and the box is a valid Rust box
+        unsafe {
+            match FfiResultTag::from_u8(self.tag) {
+                Some(FfiResultTag::Ok) => drop(Box::from_raw(self.value.ok.ptr)),
+                Some(FfiResultTag::Err) => drop(Box::from_raw(self.value.err.ptr)),
+                // which box is valid is unknown, and a panic in drop may abort
+                None => {}
+            }
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for FfiResult<T, E> {
+    #[inline]
+    fn from(result: Result<T, E>) -> Self {
+        Self::from_result(result)
+    }
+}
+
+impl<T, E> From<FfiResult<T, E>> for Result<T, E> {
+    #[inline]
+    fn from(result: FfiResult<T, E>) -> Self {
+        result.into_result()
+    }
+}
+
+/// Maps a null box to `Err(())`. The box is moved without reallocation.
+impl<T> From<OptionBox<T>> for FfiResult<T, ()> {
+    #[inline]
+    fn from(value: OptionBox<T>) -> Self {
+        if value.ptr.is_null() {
+            return Self::from_result(Err(()));
+        }
+        Self {
+            tag: FfiResultTag::Ok as u8,
+            value: FfiResultValue {
+                ok: ManuallyDrop::new(value),
+            },
+        }
+    }
+}
+
+/// Maps `Err(())` to a null box. The box is moved without reallocation.
+impl<T> From<FfiResult<T, ()>> for OptionBox<T> {
+    #[inline]
+    fn from(result: FfiResult<T, ()>) -> Self {
+        let this = ManuallyDrop::new(result);
+        // SAFETY: `tag` is matching to the initialized field and the box is a valid Rust box
+        unsafe {
+            match this.tag() {
+                FfiResultTag::Ok => OptionBox::from_raw(this.value.ok.ptr),
+                FfiResultTag::Err => {
+                    drop(Box::from_raw(this.value.err.ptr));
+                    OptionBox::none()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_result_roundtrip() {
+    let ok = FfiResult::<u32, alloc::string::String>::from_result(Ok(42));
+    assert!(ok.is_ok());
+    assert!(!ok.is_err());
+    assert_eq!(ok.as_ref(), Ok(&42));
+    assert_eq!(ok.into_result(), Ok(42));
+
+    let err = FfiResult::<u32, alloc::string::String>::from(Err("error".into()));
+    assert!(err.is_err());
+    assert_eq!(err.tag(), FfiResultTag::Err);
+    assert_eq!(Result::from(err), Err("error".into()));
+}
+
+#[test]
+fn test_result_drop() {
+    // ensure both variants including zero-sized ones are freed without double drop
+    drop(FfiResult::<alloc::vec::Vec<u8>, ()>::from_result(Ok(
+        alloc::vec![1, 2, 3],
+    )));
+    drop(FfiResult::<(), alloc::vec::Vec<u8>>::from_result(Err(
+        alloc::vec![1, 2, 3],
+    )));
+    drop(FfiResult::<(), ()>::from_result(Ok(())));
+}
+
+#[test]
+fn test_result_from_option_box() {
+    let ok = FfiResult::from(OptionBox::from_value(1));
+    assert_eq!(ok.as_ref(), Ok(&1));
+    assert_eq!(OptionBox::from(ok).into_box().as_deref(), Some(&1));
+
+    let err = FfiResult::from(OptionBox::<u32>::none());
+    assert!(err.is_err());
+    assert!(OptionBox::from(err).into_box().is_none());
+}
+
+#[test]
+fn test_result_invalid_tag() {
+    assert_eq!(FfiResultTag::from_u8(0), Some(FfiResultTag::Ok));
+    assert_eq!(FfiResultTag::from_u8(1), Some(FfiResultTag::Err));
+    assert_eq!(FfiResultTag::from_u8(2), None);
+
+    let mut result = FfiResult::<u32, u32>::from_result(Ok(1));
+    result.tag = 7; // as written by the other side
+    assert_eq!(result.raw_tag(), 7);
+    assert!(!result.is_ok());
+    assert!(!result.is_err());
+    result.tag = FfiResultTag::Ok as u8;
+    assert_eq!(result.into_result(), Ok(1));
+}
+
+#[test]
+#[should_panic(expected = "invalid FfiResult tag")]
+fn test_result_invalid_tag_into_result() {
+    let mut result = FfiResult::<u32, u32>::from_result(Err(1));
+    result.tag = 2;
+    let _ = result.into_result();
+}
@@ -0,0 +1,93 @@
+use crate::BoxedSlice;
+
+/// [`BoxedSlice<u8>`] which wipes the buffer with zeroes when dropped, for secrets like key material.
+///
+/// The layout is the same as [`BoxedSlice<u8>`].
+/// The buffer is cleared with volatile writes so the optimizer can't elide them before deallocation.
+#[repr(transparent)]
+pub struct SecretBoxedSlice(BoxedSlice<u8>);
+static_assertions::assert_eq_size!(SecretBoxedSlice, BoxedSlice<u8>);
+static_assertions::assert_impl_all!(SecretBoxedSlice: Send, Sync);
+
+impl SecretBoxedSlice {
+    #[inline(always)]
+    pub fn from_boxed_slice(slice: BoxedSlice<u8>) -> Self {
+        Self(slice)
+    }
+
+    /// Inverse of [`SecretBoxedSlice::from_boxed_slice`].
+    ///
+    /// The buffer is handed over without wiping; it is not cleared anymore when dropped.
+    #[inline(always)]
+    pub fn into_boxed_slice(self) -> BoxedSlice<u8> {
+        let this = core::mem::ManuallyDrop::new(self);
+        BoxedSlice(this.0 .0)
+    }
+}
+
+impl SecretBoxedSlice {
+    #[inline]
+    fn wipe(&mut self) {
+        for byte in self.0 .0.checked_mut_slice() {
+            // SAFETY: `byte` is a valid reference
+            unsafe { core::ptr::write_volatile(byte, 0) };
+        }
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+impl Drop for SecretBoxedSlice {
+    #[inline]
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+impl From<BoxedSlice<u8>> for SecretBoxedSlice {
+    #[inline(always)]
+    fn from(slice: BoxedSlice<u8>) -> Self {
+        Self::from_boxed_slice(slice)
+    }
+}
+
+impl From<alloc::boxed::Box<[u8]>> for SecretBoxedSlice {
+    #[inline(always)]
+    fn from(boxed: alloc::boxed::Box<[u8]>) -> Self {
+        Self::from_boxed_slice(BoxedSlice::new(boxed))
+    }
+}
+
+impl core::ops::Deref for SecretBoxedSlice {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.0 .0.checked_slice()
+    }
+}
+
+impl core::ops::DerefMut for SecretBoxedSlice {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0 .0.checked_mut_slice()
+    }
+}
+
+#[test]
+fn test_secret_boxed_slice_wipe() {
+    let mut secret = SecretBoxedSlice::from(alloc::vec![0xAA; 16].into_boxed_slice());
+    assert_eq!(&*secret, &[0xAA; 16]);
+    secret[0] = 1;
+
+    secret.wipe();
+    assert_eq!(&*secret, &[0; 16]);
+}
+
+#[test]
+fn test_secret_boxed_slice_roundtrip() {
+    let secret =
+        SecretBoxedSlice::from_boxed_slice(BoxedSlice::new(alloc::vec![1, 2].into_boxed_slice()));
+    let boxed = secret.into_boxed_slice();
+    assert_eq!(&*boxed, &[1, 2]);
+    drop(SecretBoxedSlice::from(BoxedSlice::<u8>::empty()));
+}
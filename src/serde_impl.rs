@@ -0,0 +1,85 @@
+use crate::{BoxedSlice, BoxedStr, OptionBox, SliceRef, StrRef};
+use alloc::boxed::Box;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl<T: Serialize> Serialize for SliceRef<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.checked_slice().serialize(serializer)
+    }
+}
+
+impl<T: Serialize> Serialize for BoxedSlice<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.checked_slice().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for BoxedSlice<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Box::<[T]>::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl Serialize for StrRef {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Serialize for BoxedStr {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BoxedStr {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Box::<str>::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<T: Serialize> Serialize for OptionBox<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for OptionBox<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<Box<T>>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[test]
+fn test_serde_roundtrip() {
+    let slice = SliceRef::new(&[1, 2, 3]);
+    assert_eq!(serde_json::to_string(&slice).unwrap(), "[1,2,3]");
+    let boxed: BoxedSlice<u32> = serde_json::from_str("[1,2,3]").unwrap();
+    assert_eq!(&*boxed, &[1, 2, 3]);
+    assert_eq!(serde_json::to_string(&boxed).unwrap(), "[1,2,3]");
+    let empty: BoxedSlice<u32> = serde_json::from_str("[]").unwrap();
+    assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+
+    let s = StrRef::new("hello");
+    assert_eq!(serde_json::to_string(&s).unwrap(), r#""hello""#);
+    let boxed: BoxedStr = serde_json::from_str(r#""hello""#).unwrap();
+    assert_eq!(&*boxed, "hello");
+    assert_eq!(serde_json::to_string(&boxed).unwrap(), r#""hello""#);
+
+    let some = OptionBox::from_value(1);
+    assert_eq!(serde_json::to_string(&some).unwrap(), "1");
+    let none = OptionBox::<u32>::none();
+    assert_eq!(serde_json::to_string(&none).unwrap(), "null");
+    let some: OptionBox<u32> = serde_json::from_str("1").unwrap();
+    assert_eq!(some.into_box().as_deref(), Some(&1));
+    let none: OptionBox<u32> = serde_json::from_str("null").unwrap();
+    assert!(none.into_box().is_none());
+}
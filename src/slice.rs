@@ -1,7 +1,14 @@
+use alloc::boxed::Box;
+
 /// Rust wrapper for &[T].
 #[repr(transparent)]
 pub struct SliceRef<T: 'static>(pub(crate) SliceInner<T>);
 static_assertions::assert_eq_size!(SliceRef<u8>, &[u8]);
+// SAFETY: same as &[T]
+unsafe impl<T: Sync> Send for SliceRef<T> {}
+unsafe impl<T: Sync> Sync for SliceRef<T> {}
+static_assertions::assert_impl_all!(SliceRef<u8>: Send, Sync);
+static_assertions::assert_not_impl_any!(SliceRef<core::cell::Cell<u8>>: Send, Sync);
 
 /// Rust wrapper for &[u8].
 /// Though this alias is not very useful in Rust side, it gives a simple aliased buffer type for cbindgen.
@@ -11,6 +18,10 @@ pub type ByteSliceRef = SliceRef<u8>;
 #[repr(transparent)]
 pub struct MutSliceRef<T: 'static>(SliceInner<T>);
 static_assertions::assert_eq_size!(MutSliceRef<u8>, &[u8]);
+// SAFETY: same as &mut [T]. Not `Sync` to keep it a unique handle of the buffer.
+unsafe impl<T: Send> Send for MutSliceRef<T> {}
+static_assertions::assert_impl_all!(MutSliceRef<u8>: Send);
+static_assertions::assert_not_impl_any!(MutSliceRef<u8>: Sync);
 
 /// Rust wrapper for Box<[T]>.
 ///
@@ -18,6 +29,11 @@ static_assertions::assert_eq_size!(MutSliceRef<u8>, &[u8]);
 #[repr(transparent)]
 pub struct BoxedSlice<T: 'static>(pub(crate) SliceInner<T>);
 static_assertions::assert_eq_size!(BoxedSlice<u8>, Box<[u8]>);
+// SAFETY: same as Box<[T]>
+unsafe impl<T: Send> Send for BoxedSlice<T> {}
+unsafe impl<T: Sync> Sync for BoxedSlice<T> {}
+static_assertions::assert_impl_all!(BoxedSlice<u8>: Send, Sync);
+static_assertions::assert_not_impl_any!(BoxedSlice<alloc::rc::Rc<u8>>: Send, Sync);
 
 impl<T> Clone for SliceRef<T> {
     #[inline(always)]
@@ -46,11 +62,177 @@ impl<T> SliceRef<T> {
         Self::new(crate::into_static(slice))
     }
 
+    /// Runs `f` with an unbound wrapper of `slice`, e.g. to pass a borrowed slice to a synchronous FFI call.
+    ///
+    /// The wrapper is created inside the borrow of `slice`, so only `f` needs to be audited
+    /// instead of every use of [`SliceRef::new_unbound`].
+    ///
+    /// # Safety
+    /// `f` must not keep the wrapper or any copy of it after returning, e.g. in a global or in `R`.
+    /// The type still allows it because the wrapper has no lifetime.
+    #[inline]
+    pub unsafe fn with_unbound<R>(slice: &[T], f: impl FnOnce(SliceRef<T>) -> R) -> R {
+        f(Self::new_unbound(slice))
+    }
+
+    /// Create a new wrapper from `(ptr, len)` given by the other side, after checking `len`.
+    ///
+    /// Fails when `len * size_of::<T>()` overflows or exceeds `isize::MAX`, the limit of any Rust slice.
+    /// This is the same condition as [`core::alloc::Layout::array`].
+    ///
+    /// # Safety
+    /// `ptr` must be valid for `len` elements of `T` for `'static`.
+    /// It must be aligned for `T` and non-null even if `len` is 0.
+    #[inline]
+    pub unsafe fn try_from_raw_parts(
+        ptr: *const T,
+        len: usize,
+    ) -> Result<Self, core::alloc::LayoutError> {
+        SliceInner::try_from_raw_parts(ptr as *mut T, len).map(Self)
+    }
+
     /// Inverse of [`SliceRef::new`].
+    ///
+    /// An empty slice gives `&[]` without reading the pointer, which may be null as zero-initialized by C.
+    /// Debug builds check the pointer is non-null when not empty and aligned for `T`.
     #[inline(always)]
     pub fn into_slice(self) -> &'static [T] {
-        let union = SliceUnion { inner: self.0 };
-        unsafe { union.slice }
+        self.0.debug_assert_valid();
+        self.0.checked_slice()
+    }
+
+    /// Returns the slice, or `None` if the pointer is null as zero-initialized by C.
+    ///
+    /// This distinguishes an absent slice from an empty one: a non-null pointer with length 0 yields `Some(&[])`.
+    #[inline]
+    pub fn try_as_slice(&self) -> Option<&[T]> {
+        if self.0.ptr.is_null() {
+            None
+        } else {
+            Some(self.0.checked_slice())
+        }
+    }
+
+    /// Returns the `[begin, end)` pointer pair like [`slice::as_ptr_range`], for iterator-pair style APIs.
+    ///
+    /// For an empty slice, this is `ptr..ptr` of the stored pointer, so `begin == end`.
+    /// The pointer is the aligned dangling pointer for a slice created in Rust or by the C++ header.
+    #[inline(always)]
+    pub fn as_ptr_range(&self) -> core::ops::Range<*const T> {
+        let range = self.0.ptr_range();
+        range.start as *const T..range.end as *const T
+    }
+
+    /// Returns an element or subslice like [`slice::get`], or `None` if out of bounds.
+    ///
+    /// The stored length is checked before touching the pointer,
+    /// so an empty slice never dereferences its pointer even when it is null.
+    #[inline(always)]
+    pub fn get<I: core::slice::SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+        self.0.checked_slice().get(index)
+    }
+
+    /// Returns the element at `index`, or an error with the index and the length if out of bounds.
+    ///
+    /// This never panics. In a function called from C, prefer this to `Index` because a panic
+    /// unwinding into C is undefined behavior.
+    #[inline]
+    pub fn try_index(&self, index: usize) -> Result<&T, IndexError> {
+        self.0.try_index(index)
+    }
+
+    /// Returns an iterator over the slice.
+    ///
+    /// An empty slice returns an empty iterator without forming a reference from its pointer.
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.0.checked_slice().iter()
+    }
+
+    /// Returns a new wrapper over `range`, or `None` if it is out of bounds.
+    ///
+    /// The range is checked against the stored length before offsetting the pointer.
+    #[inline]
+    pub fn subslice(&self, range: core::ops::Range<usize>) -> Option<SliceRef<T>> {
+        self.0.subslice(range).map(SliceRef)
+    }
+
+    /// Returns the first element, or `None` if empty. See [`SliceRef::get`].
+    #[inline(always)]
+    pub fn first(&self) -> Option<&T> {
+        self.0.checked_slice().first()
+    }
+
+    /// Returns the last element, or `None` if empty. See [`SliceRef::get`].
+    #[inline(always)]
+    pub fn last(&self) -> Option<&T> {
+        self.0.checked_slice().last()
+    }
+
+    /// Returns the first element and a wrapper over the rest, or `None` if empty.
+    #[inline]
+    pub fn split_first(&self) -> Option<(&T, SliceRef<T>)> {
+        let (first, rest) = self.0.checked_slice().split_first()?;
+        Some((first, SliceRef(SliceInner::from_slice(rest))))
+    }
+
+    /// Returns the last element and a wrapper over the rest, or `None` if empty.
+    #[inline]
+    pub fn split_last(&self) -> Option<(&T, SliceRef<T>)> {
+        let (last, rest) = self.0.checked_slice().split_last()?;
+        Some((last, SliceRef(SliceInner::from_slice(rest))))
+    }
+
+    /// Allocates an independent [`BoxedSlice`] of cloned elements.
+    ///
+    /// Unlike [`BoxedSlice::leak`], this doesn't take over the buffer; the copy outlives it.
+    #[inline]
+    pub fn to_boxed(&self) -> BoxedSlice<T>
+    where
+        T: Clone,
+    {
+        BoxedSlice::new(self.0.checked_slice().into())
+    }
+
+    /// Copies the elements into an array, or returns `None` if the length is not `N`.
+    ///
+    /// e.g. for a fixed-size header of a byte view. See also [`Array`](crate::Array).
+    #[inline]
+    pub fn to_array<const N: usize>(&self) -> Option<[T; N]>
+    where
+        T: Copy,
+    {
+        self.0.checked_slice().try_into().ok()
+    }
+}
+
+impl<T> Default for SliceRef<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<T: PartialEq> PartialEq for SliceRef<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.checked_slice() == other.0.checked_slice()
+    }
+}
+
+impl<T: Eq> Eq for SliceRef<T> {}
+
+impl<T: PartialOrd> PartialOrd for SliceRef<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.checked_slice().partial_cmp(other.0.checked_slice())
+    }
+}
+
+impl<T: Ord> Ord for SliceRef<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.checked_slice().cmp(other.0.checked_slice())
     }
 }
 
@@ -68,21 +250,21 @@ impl<T> From<SliceRef<T>> for &'static [T] {
     }
 }
 
-impl<T> std::convert::AsRef<[T]> for SliceRef<T> {
+impl<T> core::convert::AsRef<[T]> for SliceRef<T> {
     #[inline(always)]
     fn as_ref(&self) -> &[T] {
         self.into_slice()
     }
 }
 
-impl<T> std::borrow::Borrow<[T]> for SliceRef<T> {
+impl<T> core::borrow::Borrow<[T]> for SliceRef<T> {
     #[inline(always)]
     fn borrow(&self) -> &[T] {
         self.as_ref()
     }
 }
 
-impl<T> std::ops::Deref for SliceRef<T> {
+impl<T> core::ops::Deref for SliceRef<T> {
     type Target = [T];
 
     #[inline(always)]
@@ -91,6 +273,15 @@ impl<T> std::ops::Deref for SliceRef<T> {
     }
 }
 
+impl<T, I: core::slice::SliceIndex<[T]>> core::ops::Index<I> for SliceRef<T> {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
 impl<T> MutSliceRef<T> {
     /// Create a new wrapper for a static slice `&'static mut [T]`.
     /// See [`MutSliceRef::new_unbound`] to remove lifetime bound.
@@ -109,11 +300,149 @@ impl<T> MutSliceRef<T> {
         Self::new(crate::into_static_mut(slice))
     }
 
+    /// Runs `f` with an unbound wrapper of `slice`. See [`SliceRef::with_unbound`].
+    ///
+    /// # Safety
+    /// `f` must not keep the wrapper or any alias of the buffer from it after returning.
+    #[inline]
+    pub unsafe fn with_unbound<R>(slice: &mut [T], f: impl FnOnce(MutSliceRef<T>) -> R) -> R {
+        f(Self::new_unbound(slice))
+    }
+
     /// Inverse of [`MutSliceRef::new`].
+    ///
+    /// An empty slice gives `&mut []` without reading the pointer. See [`SliceRef::into_slice`].
     #[inline(always)]
     pub fn into_mut_slice(self) -> &'static mut [T] {
-        let union = SliceUnion { inner: self.0 };
-        unsafe { union.mut_slice }
+        self.0.debug_assert_valid();
+        self.0.checked_mut_slice()
+    }
+
+    /// Returns a mutable element or subslice like [`slice::get_mut`], or `None` if out of bounds.
+    /// See [`SliceRef::get`].
+    #[inline(always)]
+    pub fn get_mut<I: core::slice::SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+        self.0.checked_mut_slice().get_mut(index)
+    }
+
+    /// Returns an iterator over the slice. See [`SliceRef::iter`].
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.0.checked_slice().iter()
+    }
+
+    /// Returns an iterator that allows modifying each value. See [`SliceRef::iter`].
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.0.checked_mut_slice().iter_mut()
+    }
+
+    /// Fills the buffer with `value`. See [`slice::fill`].
+    #[inline]
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.0.checked_mut_slice().fill(value)
+    }
+
+    /// Copies all elements from `src` to the buffer. See [`slice::copy_from_slice`].
+    ///
+    /// # Panics
+    /// Panics if the two slices have different lengths.
+    #[inline]
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        self.0.checked_mut_slice().copy_from_slice(src)
+    }
+
+    /// Clones all elements from `src` to the buffer. See [`slice::clone_from_slice`].
+    ///
+    /// # Panics
+    /// Panics if the two slices have different lengths.
+    #[inline]
+    pub fn clone_from_slice(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        self.0.checked_mut_slice().clone_from_slice(src)
+    }
+
+    /// Reverses the order of elements in place. See [`slice::reverse`].
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.0.checked_mut_slice().reverse()
+    }
+
+    /// Rotates the buffer in place so that the element at `mid` becomes the first. See [`slice::rotate_left`].
+    ///
+    /// # Panics
+    /// Panics if `mid` is greater than the length.
+    #[inline]
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.0.checked_mut_slice().rotate_left(mid)
+    }
+
+    /// Rotates the buffer in place so that the last `k` elements become the first. See [`slice::rotate_right`].
+    ///
+    /// # Panics
+    /// Panics if `k` is greater than the length.
+    #[inline]
+    pub fn rotate_right(&mut self, k: usize) {
+        self.0.checked_mut_slice().rotate_right(k)
+    }
+
+    /// Allocates an independent [`BoxedSlice`] of cloned elements, e.g. to keep data filled by C after the call.
+    ///
+    /// See [`SliceRef::to_boxed`].
+    #[inline]
+    pub fn to_boxed(&self) -> BoxedSlice<T>
+    where
+        T: Clone,
+    {
+        BoxedSlice::new(self.0.checked_slice().into())
+    }
+}
+
+impl<T> MutSliceRef<T> {
+    /// Returns a read-only wrapper over the same buffer.
+    ///
+    /// The returned [`SliceRef`] must not be used after `self` is mutated again or outlive `self`,
+    /// because it aliases the buffer without a lifetime.
+    #[inline(always)]
+    pub fn as_slice_ref(&self) -> SliceRef<T> {
+        SliceRef(self.0)
+    }
+
+    /// Converts into a read-only wrapper over the same buffer.
+    ///
+    /// Since `self` is consumed, no mutable alias to the buffer is left from this wrapper.
+    #[inline(always)]
+    pub fn into_slice_ref(self) -> SliceRef<T> {
+        SliceRef(self.0)
+    }
+
+    /// Returns the `[begin, end)` pointer pair like [`slice::as_mut_ptr_range`].
+    /// See [`SliceRef::as_ptr_range`] for an empty slice.
+    #[inline(always)]
+    pub fn as_mut_ptr_range(&mut self) -> core::ops::Range<*mut T> {
+        self.0.ptr_range()
+    }
+}
+
+impl<T> From<MutSliceRef<T>> for SliceRef<T> {
+    #[inline(always)]
+    fn from(slice: MutSliceRef<T>) -> Self {
+        slice.into_slice_ref()
+    }
+}
+
+impl<T> Default for MutSliceRef<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(&mut [])
     }
 }
 
@@ -131,37 +460,39 @@ impl<T> From<MutSliceRef<T>> for &'static mut [T] {
     }
 }
 
-impl<T> std::convert::AsRef<[T]> for MutSliceRef<T> {
+impl<T> core::convert::AsRef<[T]> for MutSliceRef<T> {
     #[inline(always)]
     fn as_ref(&self) -> &[T] {
+        self.0.debug_assert_alive();
         let union = self.0.union();
         unsafe { union.mut_slice }
     }
 }
 
-impl<T> std::convert::AsMut<[T]> for MutSliceRef<T> {
+impl<T> core::convert::AsMut<[T]> for MutSliceRef<T> {
     #[inline(always)]
     fn as_mut(&mut self) -> &mut [T] {
+        self.0.debug_assert_alive();
         let union = self.0.union();
         unsafe { union.mut_slice }
     }
 }
 
-impl<T> std::borrow::Borrow<[T]> for MutSliceRef<T> {
+impl<T> core::borrow::Borrow<[T]> for MutSliceRef<T> {
     #[inline(always)]
     fn borrow(&self) -> &[T] {
         self.as_ref()
     }
 }
 
-impl<T> std::borrow::BorrowMut<[T]> for MutSliceRef<T> {
+impl<T> core::borrow::BorrowMut<[T]> for MutSliceRef<T> {
     #[inline(always)]
     fn borrow_mut(&mut self) -> &mut [T] {
         self.as_mut()
     }
 }
 
-impl<T> std::ops::Deref for MutSliceRef<T> {
+impl<T> core::ops::Deref for MutSliceRef<T> {
     type Target = [T];
 
     #[inline(always)]
@@ -170,29 +501,60 @@ impl<T> std::ops::Deref for MutSliceRef<T> {
     }
 }
 
-impl<T> std::ops::DerefMut for MutSliceRef<T> {
+impl<T> core::ops::DerefMut for MutSliceRef<T> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_mut()
     }
 }
 
+impl<T, I: core::slice::SliceIndex<[T]>> core::ops::Index<I> for MutSliceRef<T> {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl<T, I: core::slice::SliceIndex<[T]>> core::ops::IndexMut<I> for MutSliceRef<T> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+/// Debug builds overwrite the inner `(ptr, len)` with a poison value before freeing,
+/// so a later use of the dropped wrapper, including a double drop, panics instead of touching freed memory.
 impl<T> Drop for BoxedSlice<T> {
     #[inline(always)]
     fn drop(&mut self) {
+        self.0.debug_assert_alive();
         let union: SliceUnion<'_, _> = self.0.union();
-        let boxed: Box<[T]> = std::mem::ManuallyDrop::into_inner(unsafe { union.boxed });
+        #[cfg(debug_assertions)]
+        {
+            self.0 = SliceInner::POISONED;
+        }
+        let boxed: Box<[T]> = core::mem::ManuallyDrop::into_inner(unsafe { union.boxed });
         drop(boxed);
     }
 }
 
 impl<T> BoxedSlice<T> {
     /// Create a new wrapper for a boxed slice `Box<[T]>`.
+    ///
+    /// Debug builds check the pointer is non-null and aligned for `T`.
+    /// These are sanity checks to catch a box from a foreign allocator early, not safety guarantees.
     #[inline(always)]
-    pub fn new(boxed: std::boxed::Box<[T]>) -> Self {
+    pub fn new(boxed: alloc::boxed::Box<[T]>) -> Self {
         let inner = SliceInner::from_slice(boxed.as_ref());
         let raw = Box::into_raw(boxed);
         assert_eq!(inner.ptr, raw as *mut _);
+        debug_assert!(!inner.ptr.is_null(), "boxed slice pointer is null");
+        debug_assert!(
+            inner.len == 0 || inner.ptr.is_aligned(),
+            "boxed slice pointer is not aligned"
+        );
         Self(inner)
     }
 
@@ -201,130 +563,1351 @@ impl<T> BoxedSlice<T> {
         Self(SliceInner::empty())
     }
 
-    /// Inverse of [`BoxedSlice::new`].
-    #[inline(always)]
-    pub fn into_boxed_slice(self) -> std::boxed::Box<[T]> {
-        let union = self.0.union();
-        std::mem::ManuallyDrop::into_inner(unsafe { union.boxed })
+    /// Reconstruct a boxed slice from `(ptr, len)` given by the other side, after checking `len`.
+    /// See [`SliceRef::try_from_raw_parts`] for the checked condition.
+    ///
+    /// # Safety
+    /// `ptr` and `len` must be taken from a [`BoxedSlice<T>`] and not reconstructed yet.
+    #[inline]
+    pub unsafe fn try_from_raw_parts(
+        ptr: *mut T,
+        len: usize,
+    ) -> Result<Self, core::alloc::LayoutError> {
+        SliceInner::try_from_raw_parts(ptr, len).map(Self)
     }
-}
 
-impl<T> From<std::boxed::Box<[T]>> for BoxedSlice<T> {
+    /// Create a new boxed slice by joining `parts` with a single allocation.
     #[inline]
-    fn from(value: std::boxed::Box<[T]>) -> Self {
-        Self::new(value)
+    pub fn concat(parts: &[&[T]]) -> Self
+    where
+        T: Clone,
+    {
+        let len = parts.iter().map(|part| part.len()).sum();
+        let mut vec = alloc::vec::Vec::with_capacity(len);
+        for part in parts {
+            vec.extend_from_slice(part);
+        }
+        Self::new(vec.into_boxed_slice())
     }
-}
 
-impl<T> From<BoxedSlice<T>> for std::boxed::Box<[T]> {
+    /// Leaks the allocation and returns a borrowed wrapper of it, like [`Box::leak`].
+    ///
+    /// The buffer is intentionally never freed, so the returned [`SliceRef`] is valid for `'static`.
     #[inline(always)]
-    fn from(value: BoxedSlice<T>) -> Self {
-        value.into_boxed_slice()
+    pub fn leak(self) -> SliceRef<T> {
+        SliceRef(core::mem::ManuallyDrop::new(self).0)
     }
-}
 
-impl<T> std::convert::AsRef<[T]> for BoxedSlice<T> {
+    /// Inverse of [`BoxedSlice::new`].
     #[inline(always)]
-    fn as_ref(&self) -> &[T] {
-        let union = self.0.union();
-        unsafe { union.slice }
+    pub fn into_boxed_slice(self) -> alloc::boxed::Box<[T]> {
+        self.0.debug_assert_alive();
+        let union = core::mem::ManuallyDrop::new(self).0.union();
+        core::mem::ManuallyDrop::into_inner(unsafe { union.boxed })
     }
-}
 
-impl<T> std::convert::AsMut<[T]> for BoxedSlice<T> {
-    #[inline(always)]
-    fn as_mut(&mut self) -> &mut [T] {
-        let union = self.0.union();
-        unsafe { union.mut_slice }
+    /// Returns the element at `index`, or an error if out of bounds. See [`SliceRef::try_index`].
+    #[inline]
+    pub fn try_index(&self, index: usize) -> Result<&T, IndexError> {
+        self.0.try_index(index)
     }
-}
 
-impl<T> std::convert::AsRef<Box<[T]>> for BoxedSlice<T> {
+    /// Returns an element or subslice like [`slice::get`], or `None` if out of bounds.
+    /// See [`SliceRef::get`].
     #[inline(always)]
-    fn as_ref(&self) -> &Box<[T]> {
-        unsafe { &*(&self.0 as *const SliceInner<T> as *const Box<[T]>) }
+    pub fn get<I: core::slice::SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+        self.0.checked_slice().get(index)
     }
-}
 
-impl<T> std::convert::AsMut<Box<[T]>> for BoxedSlice<T> {
+    /// Returns a mutable element or subslice like [`slice::get_mut`], or `None` if out of bounds.
+    /// See [`SliceRef::get`].
     #[inline(always)]
-    fn as_mut(&mut self) -> &mut Box<[T]> {
-        unsafe { &mut *(&mut self.0 as *mut SliceInner<T> as *mut Box<[T]>) }
+    pub fn get_mut<I: core::slice::SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+        self.0.checked_mut_slice().get_mut(index)
     }
-}
 
-impl<T> std::borrow::Borrow<[T]> for BoxedSlice<T> {
+    /// Returns an iterator over the slice. See [`SliceRef::iter`].
     #[inline(always)]
-    fn borrow(&self) -> &[T] {
-        self.as_ref()
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.0.checked_slice().iter()
     }
-}
 
-impl<T> std::borrow::BorrowMut<[T]> for BoxedSlice<T> {
+    /// Returns an iterator that allows modifying each value. See [`SliceRef::iter`].
     #[inline(always)]
-    fn borrow_mut(&mut self) -> &mut [T] {
-        self.as_mut()
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.0.checked_mut_slice().iter_mut()
     }
-}
 
-impl<T> std::ops::Deref for BoxedSlice<T> {
-    type Target = [T];
+    /// Returns a borrowing wrapper over `range`, or `None` if it is out of bounds.
+    /// See [`SliceRef::subslice`].
+    ///
+    /// The returned [`SliceRef`] doesn't own the elements and must not outlive `self`.
+    #[inline]
+    pub fn subslice(&self, range: core::ops::Range<usize>) -> Option<SliceRef<T>> {
+        self.0.subslice(range).map(SliceRef)
+    }
 
+    /// Returns the `[begin, end)` pointer pair. See [`SliceRef::as_ptr_range`].
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        self.as_ref()
+    pub fn as_ptr_range(&self) -> core::ops::Range<*const T> {
+        let range = self.0.ptr_range();
+        range.start as *const T..range.end as *const T
     }
-}
 
-impl<T> std::ops::DerefMut for BoxedSlice<T> {
+    /// Returns the mutable `[begin, end)` pointer pair. See [`SliceRef::as_ptr_range`].
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        self.as_mut()
+    pub fn as_mut_ptr_range(&mut self) -> core::ops::Range<*mut T> {
+        self.0.ptr_range()
     }
-}
 
-#[repr(C)]
-pub(crate) struct SliceInner<T> {
-    pub(crate) ptr: *mut T,
-    pub(crate) len: usize,
-}
-static_assertions::assert_eq_size!(SliceInner<u8>, &[u8]);
+    /// Returns the first element, or `None` if empty. See [`SliceRef::get`].
+    #[inline(always)]
+    pub fn first(&self) -> Option<&T> {
+        self.0.checked_slice().first()
+    }
 
-impl<T> Clone for SliceInner<T> {
+    /// Returns the last element, or `None` if empty. See [`SliceRef::get`].
     #[inline(always)]
-    fn clone(&self) -> Self {
-        *self
+    pub fn last(&self) -> Option<&T> {
+        self.0.checked_slice().last()
     }
-}
 
-impl<T> Copy for SliceInner<T> {}
+    /// Returns the first element and a wrapper over the rest, or `None` if empty.
+    ///
+    /// The returned [`SliceRef`] doesn't own the elements and must not outlive `self`.
+    #[inline]
+    pub fn split_first(&self) -> Option<(&T, SliceRef<T>)> {
+        let (first, rest) = self.0.checked_slice().split_first()?;
+        Some((first, SliceRef(SliceInner::from_slice(rest))))
+    }
 
-impl<T> SliceInner<T> {
-    #[inline(always)]
-    pub(crate) const fn empty() -> Self {
-        Self {
-            ptr: 1 as _,
-            len: 0,
-        }
+    /// Returns the last element and a wrapper over the rest, or `None` if empty.
+    ///
+    /// The returned [`SliceRef`] doesn't own the elements and must not outlive `self`.
+    #[inline]
+    pub fn split_last(&self) -> Option<(&T, SliceRef<T>)> {
+        let (last, rest) = self.0.checked_slice().split_last()?;
+        Some((last, SliceRef(SliceInner::from_slice(rest))))
     }
 
-    #[inline(always)]
-    pub(crate) const fn from_slice(slice: &[T]) -> Self {
-        Self {
-            ptr: slice.as_ptr() as *mut _,
-            len: slice.len(),
+    /// Removes an element and returns it, replacing it with the last element like [`alloc::vec::Vec::swap_remove`].
+    ///
+    /// The slice gets shorter by one, so the buffer is reallocated on every call to keep the exact-length `Box<[T]>` layout.
+    /// See [`BoxedSlice::take_at`] to keep the length and the buffer, or collect into a `Vec` to remove many elements.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.0.len;
+        if index >= len {
+            panic!("swap_remove index (is {index}) should be < len (is {len})");
         }
+        let mut vec = alloc::vec::Vec::from(core::mem::take(self).into_boxed_slice());
+        let value = vec.swap_remove(index);
+        *self = Self::new(vec.into_boxed_slice());
+        value
     }
-    #[inline(always)]
-    const fn union(self) -> SliceUnion<'static, T> {
-        SliceUnion { inner: self }
+
+    /// Replaces an element with `replacement` and returns the old one, keeping the length and the buffer.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn take_at(&mut self, index: usize, replacement: T) -> T {
+        core::mem::replace(&mut self[index], replacement)
     }
-}
 
-union SliceUnion<'a, T> {
-    inner: SliceInner<T>,
-    slice: &'a [T],
-    mut_slice: &'a mut [T],
-    boxed: std::mem::ManuallyDrop<std::boxed::Box<[T]>>,
-}
-static_assertions::assert_eq_size!(SliceInner<u8>, SliceUnion<u8>);
+    /// Sorts the slice in place. See [`slice::sort_unstable`].
+    #[inline]
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.0.checked_mut_slice().sort_unstable()
+    }
+
+    /// Takes the elements out and returns them sorted without duplicates, leaving `self` empty.
+    ///
+    /// The buffer is reallocated to the new length when duplicates are removed, freeing the original allocation.
+    #[inline]
+    pub fn dedup(&mut self) -> BoxedSlice<T>
+    where
+        T: Ord,
+    {
+        let mut vec = alloc::vec::Vec::from(core::mem::take(self).into_boxed_slice());
+        vec.sort_unstable();
+        vec.dedup();
+        Self::new(vec.into_boxed_slice())
+    }
+
+    /// Consumes the slice and collects the elements matching `pred` into a new boxed slice.
+    ///
+    /// The other elements are dropped and the original allocation is freed.
+    #[inline]
+    pub fn filtered<F: FnMut(&T) -> bool>(self, mut pred: F) -> BoxedSlice<T> {
+        let vec = alloc::vec::Vec::from(self.into_boxed_slice());
+        vec.into_iter().filter(|item| pred(item)).collect()
+    }
+
+    /// Copies the elements into an array, or returns `None` if the length is not `N`.
+    /// See [`SliceRef::to_array`].
+    #[inline]
+    pub fn to_array<const N: usize>(&self) -> Option<[T; N]>
+    where
+        T: Copy,
+    {
+        self.0.checked_slice().try_into().ok()
+    }
+
+    /// Returns the bytes held on the heap, `len * size_of::<T>()`. The elements are not read,
+    /// so allocations owned by the elements themselves are not counted.
+    #[inline(always)]
+    pub const fn heap_size(&self) -> usize {
+        self.0.len * core::mem::size_of::<T>()
+    }
+}
+
+impl<T> Default for BoxedSlice<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> From<alloc::boxed::Box<[T]>> for BoxedSlice<T> {
+    #[inline]
+    fn from(value: alloc::boxed::Box<[T]>) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Always results in an owned slice; a borrowed value is cloned.
+///
+/// To avoid the copy, match the value and wrap a borrowed `&'static [T]` by [`SliceRef::new`] instead.
+impl<T: Clone> From<alloc::borrow::Cow<'static, [T]>> for BoxedSlice<T> {
+    #[inline]
+    fn from(slice: alloc::borrow::Cow<'static, [T]>) -> Self {
+        Self::new(slice.into_owned().into_boxed_slice())
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for BoxedSlice<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        Self::new(Box::new(array))
+    }
+}
+
+/// Fails and gives back the slice when its length is not `N`.
+impl<T, const N: usize> TryFrom<BoxedSlice<T>> for [T; N] {
+    type Error = BoxedSlice<T>;
+
+    #[inline]
+    fn try_from(slice: BoxedSlice<T>) -> Result<Self, Self::Error> {
+        match Box::<[T; N]>::try_from(slice.into_boxed_slice()) {
+            Ok(array) => Ok(*array),
+            Err(slice) => Err(BoxedSlice::new(slice)),
+        }
+    }
+}
+
+/// Copies the elements like [`SliceRef::to_array`], for [`Array`](crate::Array) as well.
+impl<T: Copy, const N: usize> TryFrom<SliceRef<T>> for [T; N] {
+    type Error = core::array::TryFromSliceError;
+
+    #[inline]
+    fn try_from(slice: SliceRef<T>) -> Result<Self, Self::Error> {
+        slice.0.checked_slice().try_into()
+    }
+}
+
+impl<T: PartialEq> PartialEq for BoxedSlice<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.checked_slice() == other.0.checked_slice()
+    }
+}
+
+impl<T: Eq> Eq for BoxedSlice<T> {}
+
+impl<T: PartialEq> PartialEq<SliceRef<T>> for BoxedSlice<T> {
+    #[inline]
+    fn eq(&self, other: &SliceRef<T>) -> bool {
+        self.0.checked_slice() == other.0.checked_slice()
+    }
+}
+
+impl<T: PartialEq> PartialEq<BoxedSlice<T>> for SliceRef<T> {
+    #[inline]
+    fn eq(&self, other: &BoxedSlice<T>) -> bool {
+        self.0.checked_slice() == other.0.checked_slice()
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for BoxedSlice<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.checked_slice().partial_cmp(other.0.checked_slice())
+    }
+}
+
+impl<T: Ord> Ord for BoxedSlice<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.checked_slice().cmp(other.0.checked_slice())
+    }
+}
+
+impl<T> FromIterator<T> for BoxedSlice<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<T> From<BoxedSlice<T>> for alloc::boxed::Box<[T]> {
+    #[inline(always)]
+    fn from(value: BoxedSlice<T>) -> Self {
+        value.into_boxed_slice()
+    }
+}
+
+impl<T> core::convert::AsRef<[T]> for BoxedSlice<T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        self.0.debug_assert_alive();
+        let union = self.0.union();
+        unsafe { union.slice }
+    }
+}
+
+impl<T> core::convert::AsMut<[T]> for BoxedSlice<T> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.0.debug_assert_alive();
+        let union = self.0.union();
+        unsafe { union.mut_slice }
+    }
+}
+
+/// Reinterprets the inner `(ptr, len)` in place as the `Box<[T]>` it was created from.
+///
+/// The reference borrows `self`, so it can't coexist with a `&mut [T]` from [`AsMut<[T]>`] or [`DerefMut`](core::ops::DerefMut),
+/// which borrow `self` mutably as well. The pointer is never null for a `BoxedSlice` created in Rust;
+/// debug builds check it because a null `Box` is undefined behavior even when empty.
+impl<T> core::convert::AsRef<Box<[T]>> for BoxedSlice<T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Box<[T]> {
+        debug_assert!(!self.0.ptr.is_null(), "boxed slice pointer is null");
+        self.0.debug_assert_alive();
+        // SAFETY: `SliceInner<T>` has the same layout as `Box<[T]>` and holds a valid box
+        unsafe { &*(&self.0 as *const SliceInner<T> as *const Box<[T]>) }
+    }
+}
+
+/// Same as [`AsRef<Box<[T]>>`]. Replacing the box through the reference drops the old allocation
+/// and stores the new `(ptr, len)` in `self`, so `self` stays consistent.
+impl<T> core::convert::AsMut<Box<[T]>> for BoxedSlice<T> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut Box<[T]> {
+        debug_assert!(!self.0.ptr.is_null(), "boxed slice pointer is null");
+        self.0.debug_assert_alive();
+        // SAFETY: `SliceInner<T>` has the same layout as `Box<[T]>` and holds a valid box
+        unsafe { &mut *(&mut self.0 as *mut SliceInner<T> as *mut Box<[T]>) }
+    }
+}
+
+impl<T> core::borrow::Borrow<[T]> for BoxedSlice<T> {
+    #[inline(always)]
+    fn borrow(&self) -> &[T] {
+        self.as_ref()
+    }
+}
+
+impl<T> core::borrow::BorrowMut<[T]> for BoxedSlice<T> {
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut()
+    }
+}
+
+impl<T> core::ops::Deref for BoxedSlice<T> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<T> core::ops::DerefMut for BoxedSlice<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut()
+    }
+}
+
+impl<T, I: core::slice::SliceIndex<[T]>> core::ops::Index<I> for BoxedSlice<T> {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        &AsRef::<[T]>::as_ref(self)[index]
+    }
+}
+
+impl<T, I: core::slice::SliceIndex<[T]>> core::ops::IndexMut<I> for BoxedSlice<T> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut AsMut::<[T]>::as_mut(self)[index]
+    }
+}
+
+/// Raw `{ ptr, len }` layout shared by every slice wrapper.
+///
+/// This is for downstream `#[repr(C)]` structs which want to embed a slice field without a wrapper type.
+/// Unlike the wrappers, nothing about the pointer is guaranteed; see the reconstructors for the requirements.
+#[repr(C)]
+pub struct RawSlice<T> {
+    pub ptr: *mut T,
+    pub len: usize,
+}
+static_assertions::assert_eq_size!(RawSlice<u8>, &[u8]);
+
+impl<T> Clone for RawSlice<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RawSlice<T> {}
+
+impl<T> RawSlice<T> {
+    /// Inverse of `From<SliceRef<T>>`.
+    ///
+    /// An empty slice gets the aligned dangling pointer, so a null or unaligned `ptr` is accepted when `len` is 0.
+    ///
+    /// # Safety
+    /// `ptr` and `len` must be a valid `&'static [T]`, or `len` must be 0.
+    #[inline(always)]
+    pub unsafe fn into_slice_ref(self) -> SliceRef<T> {
+        if self.len == 0 {
+            return SliceRef(SliceInner::empty());
+        }
+        SliceRef(SliceInner {
+            ptr: self.ptr,
+            len: self.len,
+        })
+    }
+
+    /// Inverse of `From<BoxedSlice<T>>`.
+    ///
+    /// # Safety
+    /// `ptr` and `len` must be taken from a [`BoxedSlice<T>`] and not reconstructed yet.
+    #[inline(always)]
+    pub unsafe fn into_boxed_slice(self) -> BoxedSlice<T> {
+        BoxedSlice(SliceInner {
+            ptr: self.ptr,
+            len: self.len,
+        })
+    }
+}
+
+impl<T> From<SliceRef<T>> for RawSlice<T> {
+    #[inline(always)]
+    fn from(slice: SliceRef<T>) -> Self {
+        Self {
+            ptr: slice.0.ptr,
+            len: slice.0.len,
+        }
+    }
+}
+
+/// The ownership is moved to the raw slice. Use [`RawSlice::into_boxed_slice`] to free it.
+impl<T> From<BoxedSlice<T>> for RawSlice<T> {
+    #[inline(always)]
+    fn from(slice: BoxedSlice<T>) -> Self {
+        let inner = core::mem::ManuallyDrop::new(slice).0;
+        Self {
+            ptr: inner.ptr,
+            len: inner.len,
+        }
+    }
+}
+
+/// Lifetime-bound counterpart of [`SliceRef<T>`] to stage a borrowed slice before passing it to the other side.
+///
+/// The layout is the same as [`SliceRef<T>`], but the borrow is checked until [`SliceRefLt::erase`].
+#[repr(transparent)]
+pub struct SliceRefLt<'a, T>(SliceInner<T>, core::marker::PhantomData<&'a [T]>);
+static_assertions::assert_eq_size!(SliceRefLt<'static, u8>, SliceRef<u8>);
+// SAFETY: same as &[T]
+unsafe impl<T: Sync> Send for SliceRefLt<'_, T> {}
+unsafe impl<T: Sync> Sync for SliceRefLt<'_, T> {}
+
+impl<T> Clone for SliceRefLt<'_, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SliceRefLt<'_, T> {}
+
+impl<'a, T> SliceRefLt<'a, T> {
+    #[inline(always)]
+    pub const fn new(slice: &'a [T]) -> Self {
+        Self(SliceInner::from_slice(slice), core::marker::PhantomData)
+    }
+
+    #[inline(always)]
+    pub fn as_slice(&self) -> &'a [T] {
+        self.0.checked_slice()
+    }
+
+    /// Converts into [`SliceRef<T>`] without the lifetime, to hand it across the boundary. The layout is not changed.
+    ///
+    /// # Safety
+    /// The returned object must not outlive `'a`, the same as [`SliceRef::new_unbound`].
+    #[inline(always)]
+    pub unsafe fn erase(self) -> SliceRef<T>
+    where
+        T: 'static,
+    {
+        SliceRef(self.0)
+    }
+}
+
+impl<'a, T> From<&'a [T]> for SliceRefLt<'a, T> {
+    #[inline(always)]
+    fn from(slice: &'a [T]) -> Self {
+        Self::new(slice)
+    }
+}
+
+impl<T> From<SliceRef<T>> for SliceRefLt<'_, T> {
+    #[inline(always)]
+    fn from(slice: SliceRef<T>) -> Self {
+        Self(slice.0, core::marker::PhantomData)
+    }
+}
+
+impl<T> core::ops::Deref for SliceRefLt<'_, T> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+/// An error of out of bounds access, returned instead of panicking.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexError {
+    /// The requested index. For a range, this is the bound which failed.
+    pub index: usize,
+    /// The actual length.
+    pub len: usize,
+}
+
+impl core::fmt::Display for IndexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds for length {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl core::error::Error for IndexError {}
+
+#[repr(C)]
+pub(crate) struct SliceInner<T> {
+    pub(crate) ptr: *mut T,
+    pub(crate) len: usize,
+}
+static_assertions::assert_eq_size!(SliceInner<u8>, &[u8]);
+
+impl<T> Clone for SliceInner<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SliceInner<T> {}
+
+impl<T> SliceInner<T> {
+    /// An empty slice with a dangling pointer aligned for `T`, the same as `&[]` in Rust.
+    #[inline(always)]
+    pub(crate) const fn empty() -> Self {
+        Self {
+            ptr: core::ptr::NonNull::dangling().as_ptr(),
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) const fn from_slice(slice: &[T]) -> Self {
+        Self {
+            ptr: slice.as_ptr() as *mut _,
+            len: slice.len(),
+        }
+    }
+    #[inline(always)]
+    const fn union(self) -> SliceUnion<'static, T> {
+        SliceUnion { inner: self }
+    }
+
+    #[inline]
+    fn try_from_raw_parts(ptr: *mut T, len: usize) -> Result<Self, core::alloc::LayoutError> {
+        core::alloc::Layout::array::<T>(len)?;
+        Ok(Self { ptr, len })
+    }
+
+    #[inline]
+    fn try_index<'a>(self, index: usize) -> Result<&'a T, IndexError>
+    where
+        T: 'a,
+    {
+        self.checked_slice().get(index).ok_or(IndexError {
+            index,
+            len: self.len,
+        })
+    }
+
+    /// `ptr.add(0)` is valid for any pointer, so an empty slice never offsets its pointer.
+    #[inline(always)]
+    fn ptr_range(self) -> core::ops::Range<*mut T> {
+        let end = if self.len == 0 {
+            self.ptr
+        } else {
+            unsafe { self.ptr.add(self.len) } // SAFETY: `ptr` is valid for `len` elements
+        };
+        self.ptr..end
+    }
+
+    #[inline]
+    fn subslice(self, range: core::ops::Range<usize>) -> Option<Self> {
+        if range.start > range.end || range.end > self.len {
+            return None;
+        }
+        Some(Self {
+            ptr: self.ptr.wrapping_add(range.start),
+            len: range.end - range.start,
+        })
+    }
+
+    /// Stored by `Drop for BoxedSlice<T>` in debug builds. The pointer is odd, so it is never a valid pointer for `T` but `u8`,
+    /// and the length is too large for any allocation.
+    #[cfg(debug_assertions)]
+    const POISONED: Self = Self {
+        ptr: core::ptr::without_provenance_mut(0xDEAD_BEEF_DEAD_BEEF_u64 as usize),
+        len: usize::MAX,
+    };
+
+    /// Catches a use of a dropped [`BoxedSlice<T>`] in debug builds. No-op in release builds.
+    #[inline(always)]
+    pub(crate) fn debug_assert_alive(self) {
+        #[cfg(debug_assertions)]
+        assert!(
+            self.ptr != Self::POISONED.ptr || self.len != Self::POISONED.len,
+            "use of a dropped BoxedSlice"
+        );
+    }
+
+    /// Catches an invalid `(ptr, len)` pair from the other side in debug builds.
+    /// The aligned dangling pointer of an empty slice is accepted.
+    #[inline(always)]
+    pub(crate) fn debug_assert_valid(self) {
+        debug_assert!(
+            self.len == 0 || !self.ptr.is_null(),
+            "slice pointer is null for non-empty length {}",
+            self.len
+        );
+        debug_assert!(self.ptr.is_aligned(), "slice pointer is not aligned");
+    }
+
+    /// Returns the slice without reading `ptr` when `len` is 0.
+    #[inline(always)]
+    pub(crate) fn checked_slice<'a>(self) -> &'a [T]
+    where
+        T: 'a,
+    {
+        self.debug_assert_alive();
+        if self.len == 0 {
+            &[]
+        } else {
+            unsafe { SliceUnion { inner: self }.slice }
+        }
+    }
+
+    /// Returns the mutable slice without reading `ptr` when `len` is 0.
+    #[inline(always)]
+    pub(crate) fn checked_mut_slice<'a>(self) -> &'a mut [T]
+    where
+        T: 'a,
+    {
+        self.debug_assert_alive();
+        if self.len == 0 {
+            &mut []
+        } else {
+            unsafe { SliceUnion { inner: self }.mut_slice }
+        }
+    }
+}
+
+union SliceUnion<'a, T> {
+    inner: SliceInner<T>,
+    slice: &'a [T],
+    mut_slice: &'a mut [T],
+    boxed: core::mem::ManuallyDrop<alloc::boxed::Box<[T]>>,
+}
+static_assertions::assert_eq_size!(SliceInner<u8>, SliceUnion<u8>);
+
+#[test]
+fn test_boxed_slice_into_boxed_slice_drops_once() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct Counted;
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let boxed: Box<[Counted]> = Box::new([Counted, Counted]);
+    let boxed = BoxedSlice::new(boxed).into_boxed_slice();
+    assert_eq!(DROPS.load(Ordering::Relaxed), 0);
+    drop(boxed);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn test_slice_index() {
+    let slice = SliceRef::new(&[1, 2, 3, 4]);
+    assert_eq!(slice[1], 2);
+    assert_eq!(&slice[1..3], &[2, 3]);
+    assert_eq!(&slice[..], &[1, 2, 3, 4]);
+
+    let mut boxed = BoxedSlice::new(alloc::vec![1, 2, 3].into_boxed_slice());
+    boxed[0] = 10;
+    boxed[1..].copy_from_slice(&[20, 30]);
+    assert_eq!(&boxed[..], &[10, 20, 30]);
+
+    let mut buffer = [0u8; 4];
+    let mut mut_slice = unsafe { MutSliceRef::new_unbound(&mut buffer) };
+    mut_slice[3] = 1;
+    mut_slice[..2].fill(2);
+    assert_eq!(mut_slice[3], 1);
+    assert_eq!(buffer, [2, 2, 0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+fn test_slice_index_out_of_bounds() {
+    let slice = SliceRef::new(&[1, 2]);
+    let _ = slice[2];
+}
+
+#[test]
+fn test_slice_get() {
+    let slice = SliceRef::new(&[1, 2, 3]);
+    assert_eq!(slice.get(0), Some(&1));
+    assert_eq!(slice.get(2), Some(&3));
+    assert_eq!(slice.get(3), None);
+    assert_eq!(slice.get(1..3), Some(&[2, 3][..]));
+    assert_eq!(slice.get(3..), Some(&[][..]));
+    assert_eq!(slice.get(2..4), None);
+
+    let mut boxed = BoxedSlice::new(alloc::vec![1, 2, 3].into_boxed_slice());
+    *boxed.get_mut(2).unwrap() = 30;
+    assert_eq!(boxed.get(2), Some(&30));
+    assert_eq!(boxed.get_mut(3), None);
+
+    let mut buffer = [0u8; 2];
+    let mut mut_slice = unsafe { MutSliceRef::new_unbound(&mut buffer) };
+    mut_slice.get_mut(..).unwrap().fill(1);
+    assert_eq!(mut_slice.get_mut(2), None);
+    assert_eq!(buffer, [1, 1]);
+}
+
+#[test]
+fn test_empty_slice_get() {
+    let empty = SliceRef::<u32>::new(&[]);
+    assert_eq!(empty.get(0), None);
+    assert_eq!(empty.get(..), Some(&[][..]));
+
+    // zero-initialized by C side
+    let null = SliceRef::<u32>(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    });
+    assert_eq!(null.get(0), None);
+    assert_eq!(null.get(..), Some(&[][..]));
+
+    let mut null = MutSliceRef::<u32>(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    });
+    assert_eq!(null.get_mut(0), None);
+}
+
+#[test]
+fn test_slice_default() {
+    #[derive(Default)]
+    struct Args {
+        input: SliceRef<u8>,
+        output: MutSliceRef<u8>,
+        owned: BoxedSlice<u8>,
+    }
+    let args = Args::default();
+    assert!(args.input.is_empty());
+    assert!(args.output.is_empty());
+    assert!(args.owned.is_empty());
+    drop(args);
+}
+
+#[test]
+fn test_slice_iter() {
+    let slice = SliceRef::new(&[1, 2, 3]);
+    assert_eq!(slice.iter().sum::<i32>(), 6);
+
+    let mut boxed = BoxedSlice::new(alloc::vec![1, 2, 3].into_boxed_slice());
+    boxed.iter_mut().for_each(|x| *x *= 10);
+    assert_eq!(
+        boxed.iter().copied().collect::<alloc::vec::Vec<_>>(),
+        [10, 20, 30]
+    );
+
+    // zero-initialized by C side
+    let mut null = MutSliceRef::<u64>(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    });
+    assert_eq!(null.iter().count(), 0);
+    assert_eq!(null.iter_mut().count(), 0);
+    let empty = BoxedSlice::<u64>::empty();
+    assert_eq!(empty.iter().count(), 0);
+}
+
+#[test]
+fn test_boxed_slice_concat() {
+    let head = SliceRef::new(b"hello");
+    let tail = BoxedSlice::new(b" world".to_vec().into_boxed_slice());
+    let joined = BoxedSlice::concat(&[&head, &[], &tail]);
+    assert_eq!(&*joined, b"hello world");
+
+    let empty = BoxedSlice::<u8>::concat(&[]);
+    assert!(empty.is_empty());
+
+    let collected: BoxedSlice<_> = (1..=3).map(|x| x * 10).collect();
+    assert_eq!(&*collected, &[10, 20, 30]);
+}
+
+#[test]
+fn test_empty_slice_alignment() {
+    // the empty pointer must be valid to deref for any alignment
+    let empty = SliceRef::<i32>(SliceInner::empty());
+    assert_eq!(empty.as_ptr() as usize, core::mem::align_of::<i32>());
+    assert_eq!(empty.as_ref(), &[] as &[i32]);
+
+    let empty = BoxedSlice::<u64>::empty();
+    assert_eq!(empty.as_ptr() as usize, core::mem::align_of::<u64>());
+    assert_eq!(AsRef::<[u64]>::as_ref(&empty), &[] as &[u64]);
+    drop(empty);
+}
+
+#[test]
+fn test_mut_slice_fill() {
+    let mut buffer = [0u8; 4];
+    let mut output = unsafe { MutSliceRef::new_unbound(&mut buffer) };
+    output.fill(7);
+    output.copy_from_slice(&[1, 2, 3, 4]);
+    output[..2].fill(0);
+    assert_eq!(buffer, [0, 0, 3, 4]);
+
+    let mut strings = [alloc::string::String::new(), alloc::string::String::new()];
+    let mut output = unsafe { MutSliceRef::new_unbound(&mut strings) };
+    output.clone_from_slice(&["a".into(), "b".into()]);
+    assert_eq!(strings, ["a", "b"]);
+
+    MutSliceRef::<u8>::default().copy_from_slice(&[]);
+}
+
+#[test]
+#[should_panic(expected = "source slice length (1) does not match destination slice length (2)")]
+fn test_mut_slice_copy_from_slice_mismatch() {
+    let mut buffer = [0u8; 2];
+    unsafe { MutSliceRef::new_unbound(&mut buffer) }.copy_from_slice(&[1]);
+}
+
+#[test]
+fn test_slice_ord() {
+    let empty = SliceRef::<u8>::new(&[]);
+    let a = SliceRef::new(b"a");
+    let ab = SliceRef::new(b"ab");
+    assert!(empty < a && a < ab);
+    assert!(a == SliceRef::new(b"a"));
+    assert!(empty == SliceRef::default());
+
+    let mut boxed: alloc::vec::Vec<BoxedSlice<u8>> = [&b"b"[..], b"", b"ab", b"a"]
+        .iter()
+        .map(|bytes| BoxedSlice::new(bytes.to_vec().into_boxed_slice()))
+        .collect();
+    boxed.sort();
+    assert!(boxed.iter().map(|b| &**b).eq([&b""[..], b"a", b"ab", b"b"]));
+}
+
+#[test]
+fn test_raw_slice() {
+    #[repr(C)]
+    struct Record {
+        id: u32,
+        payload: RawSlice<u8>,
+    }
+
+    let record = Record {
+        id: 1,
+        payload: SliceRef::new(b"data").into(),
+    };
+    assert_eq!(record.id, 1);
+    assert_eq!(&*unsafe { record.payload.into_slice_ref() }, b"data");
+
+    let raw = RawSlice::from(BoxedSlice::new(alloc::vec![1, 2, 3].into_boxed_slice()));
+    assert_eq!(raw.len, 3);
+    let boxed = unsafe { raw.into_boxed_slice() };
+    assert_eq!(&*boxed, &[1, 2, 3]);
+
+    // zero-initialized by C side
+    let null = RawSlice::<u32> {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    };
+    let slice = unsafe { null.into_slice_ref() };
+    assert!(!slice.0.ptr.is_null());
+    assert!(slice.0.ptr.is_aligned());
+    assert!(slice.as_ref().is_empty());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "slice pointer is null for non-empty length 3")]
+fn test_into_slice_null() {
+    let null = SliceRef::<u32>(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 3,
+    });
+    let _ = null.into_slice();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "slice pointer is not aligned")]
+fn test_into_slice_unaligned() {
+    let buffer = [0u32; 2];
+    let unaligned = SliceRef::<u32>(SliceInner {
+        ptr: (buffer.as_ptr() as *mut u8).wrapping_add(1) as *mut u32,
+        len: 1,
+    });
+    let _ = unaligned.into_slice();
+}
+
+#[test]
+fn test_boxed_slice_filtered() {
+    let boxed: BoxedSlice<alloc::string::String> = ["a", "bb", "ccc", "dd"]
+        .iter()
+        .map(|s| (*s).into())
+        .collect();
+    let filtered = boxed.filtered(|s| s.len() == 2);
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(&*filtered, &["bb", "dd"]);
+
+    let none = filtered.filtered(|_| false);
+    assert!(none.is_empty());
+    let empty = BoxedSlice::<alloc::string::String>::empty().filtered(|_| true);
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn test_subslice() {
+    let slice = SliceRef::new(&[1, 2, 3, 4]);
+    assert_eq!(&*slice.subslice(1..3).unwrap(), &[2, 3]);
+    assert_eq!(&*slice.subslice(0..4).unwrap(), &[1, 2, 3, 4]);
+    assert!(slice.subslice(2..5).is_none());
+    assert!(slice.subslice(3..2).is_none());
+    assert!(slice.subslice(4..4).unwrap().is_empty());
+
+    let boxed = BoxedSlice::new(alloc::vec![1, 2, 3].into_boxed_slice());
+    let tail = boxed.subslice(1..3).unwrap();
+    assert_eq!(&*tail, &[2, 3]);
+    assert!(boxed.subslice(0..4).is_none());
+    drop(boxed);
+
+    let empty = BoxedSlice::<u32>::empty();
+    assert!(empty.subslice(0..0).unwrap().is_empty());
+    assert!(empty.subslice(0..1).is_none());
+}
+
+#[test]
+fn test_mut_slice_into_slice_ref() {
+    let mut buffer = [0u8; 3];
+    let mut output = unsafe { MutSliceRef::new_unbound(&mut buffer) };
+    output.copy_from_slice(b"abc");
+    assert_eq!(&*output.as_slice_ref(), b"abc");
+    let input: SliceRef<u8> = output.into();
+    assert_eq!(&*input, b"abc");
+}
+
+#[test]
+fn test_try_from_raw_parts() {
+    let data = [1u32, 2, 3];
+    let slice = unsafe { SliceRef::try_from_raw_parts(data.as_ptr(), data.len()) }.unwrap();
+    assert_eq!(&*slice, &[1, 2, 3]);
+    assert!(unsafe { SliceRef::try_from_raw_parts(data.as_ptr(), usize::MAX / 2) }.is_err());
+    assert!(unsafe {
+        SliceRef::<u8>::try_from_raw_parts(data.as_ptr() as _, isize::MAX as usize + 1)
+    }
+    .is_err());
+
+    let boxed = BoxedSlice::new(alloc::vec![1u64, 2].into_boxed_slice());
+    let raw = RawSlice::from(boxed);
+    assert!(unsafe { BoxedSlice::try_from_raw_parts(raw.ptr, usize::MAX / 4) }.is_err());
+    let boxed = unsafe { BoxedSlice::try_from_raw_parts(raw.ptr, raw.len) }.unwrap();
+    assert_eq!(&*boxed, &[1, 2]);
+}
+
+#[test]
+fn test_boxed_slice_move_out() {
+    let mut boxed: BoxedSlice<alloc::string::String> =
+        ["a", "b", "c"].iter().map(|s| (*s).into()).collect();
+    assert_eq!(boxed.take_at(1, "B".into()), "b");
+    assert_eq!(&*boxed, &["a", "B", "c"]);
+
+    assert_eq!(boxed.swap_remove(0), "a");
+    assert_eq!(&*boxed, &["c", "B"]);
+    assert_eq!(boxed.swap_remove(1), "B");
+    assert_eq!(boxed.swap_remove(0), "c");
+    assert!(boxed.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "swap_remove index (is 1) should be < len (is 1)")]
+fn test_boxed_slice_swap_remove_out_of_bounds() {
+    let mut boxed = BoxedSlice::new(alloc::vec![1].into_boxed_slice());
+    boxed.swap_remove(1);
+}
+
+#[test]
+#[should_panic]
+fn test_boxed_slice_take_at_out_of_bounds() {
+    let mut boxed = BoxedSlice::new(alloc::vec![1].into_boxed_slice());
+    boxed.take_at(1, 0);
+}
+
+#[test]
+fn test_boxed_slice_array() {
+    let boxed = BoxedSlice::from([1u8, 2, 3]);
+    assert_eq!(&*boxed, &[1, 2, 3]);
+    let array: Result<[u8; 3], _> = boxed.try_into();
+    assert!(matches!(array, Ok([1, 2, 3])));
+
+    let boxed = BoxedSlice::from([alloc::string::String::from("a")]);
+    let Err(boxed) = <[_; 2]>::try_from(boxed) else {
+        panic!("length mismatch must fail");
+    };
+    assert_eq!(&*boxed, &["a"]);
+
+    let empty = BoxedSlice::<u32>::from([]);
+    assert!(<[u32; 0]>::try_from(empty).is_ok());
+}
+
+#[test]
+fn test_boxed_slice_leak() {
+    let leaked = BoxedSlice::new(alloc::vec![1, 2, 3].into_boxed_slice()).leak();
+    assert_eq!(leaked.into_slice(), &[1, 2, 3]);
+    // give back the allocation not to be reported by leak checkers
+    drop(unsafe { RawSlice::from(leaked).into_boxed_slice() });
+}
+
+#[test]
+fn test_slice_try_as_slice() {
+    assert_eq!(SliceRef::new(&[1, 2]).try_as_slice(), Some(&[1, 2][..]));
+    assert!(SliceRef::<u32>::new(&[]).try_as_slice().unwrap().is_empty());
+
+    // zero-initialized by C side
+    let null = SliceRef::<u32>(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    });
+    assert_eq!(null.try_as_slice(), None);
+}
+
+#[test]
+fn test_boxed_slice_from_cow() {
+    use alloc::borrow::Cow;
+    let borrowed: Cow<'static, [u8]> = Cow::Borrowed(b"borrowed");
+    assert_eq!(&*BoxedSlice::from(borrowed), b"borrowed");
+    let owned: Cow<'static, [u8]> = Cow::Owned(b"owned".to_vec());
+    assert_eq!(&*BoxedSlice::from(owned), b"owned");
+}
+
+#[test]
+fn test_slice_split_first_last() {
+    let mut bytes = ByteSliceRef::new(b"abc");
+    assert_eq!(bytes.first(), Some(&b'a'));
+    assert_eq!(bytes.last(), Some(&b'c'));
+    let mut consumed = alloc::vec::Vec::new();
+    while let Some((&byte, rest)) = bytes.split_first() {
+        consumed.push(byte);
+        bytes = rest;
+    }
+    assert_eq!(consumed, b"abc");
+    assert!(bytes.is_empty());
+    assert_eq!(bytes.first(), None);
+    assert!(bytes.split_last().is_none());
+
+    let boxed = BoxedSlice::new(alloc::vec![1, 2, 3].into_boxed_slice());
+    let (last, rest) = boxed.split_last().unwrap();
+    assert_eq!(*last, 3);
+    assert_eq!(&*rest, &[1, 2]);
+    assert_eq!(
+        boxed
+            .split_first()
+            .map(|(first, rest)| (*first, rest.len())),
+        Some((1, 2))
+    );
+
+    // zero-initialized by C side
+    let null = SliceRef::<u32>(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    });
+    assert_eq!(null.last(), None);
+    assert!(null.split_first().is_none());
+}
+
+#[test]
+fn test_boxed_slice_sort_dedup() {
+    let mut boxed = BoxedSlice::from([3, 1, 2, 3, 1]);
+    boxed.sort_unstable();
+    assert_eq!(&*boxed, &[1, 1, 2, 3, 3]);
+
+    let mut names: BoxedSlice<alloc::string::String> = ["b", "a", "b", "c", "a"]
+        .iter()
+        .map(|s| (*s).into())
+        .collect();
+    let unique = names.dedup();
+    assert_eq!(&*unique, &["a", "b", "c"]);
+    assert!(names.is_empty());
+
+    let mut sorted = BoxedSlice::from([3, 1, 2]);
+    assert_eq!(&*sorted.dedup(), &[1, 2, 3]);
+
+    let mut empty = BoxedSlice::<u8>::empty();
+    assert!(empty.dedup().is_empty());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_slice_to_boxed() {
+    let mut buffer = [1u8, 2, 3];
+    let mut slice = unsafe { MutSliceRef::new_unbound(&mut buffer) };
+    let copy = slice.to_boxed();
+    slice.fill(0);
+    assert_eq!(&*copy, &[1, 2, 3]);
+
+    let copy = slice.as_slice_ref().to_boxed();
+    slice[0] = 9;
+    assert_eq!(&*copy, &[0, 0, 0]);
+    assert_eq!(buffer, [9, 0, 0]);
+
+    assert!(MutSliceRef::<u8>::default().to_boxed().is_empty());
+    assert!(SliceRef::<u8>::default().to_boxed().is_empty());
+}
+
+#[test]
+fn test_with_unbound() {
+    extern "C" fn sum(slice: SliceRef<u32>) -> u32 {
+        slice.iter().sum()
+    }
+    extern "C" fn double(mut slice: MutSliceRef<u32>) {
+        slice.iter_mut().for_each(|v| *v *= 2);
+    }
+
+    let mut values = alloc::vec![1, 2, 3];
+    unsafe { MutSliceRef::with_unbound(&mut values, |slice| double(slice)) };
+    assert_eq!(
+        unsafe { SliceRef::with_unbound(&values, |slice| sum(slice)) },
+        12
+    );
+    assert_eq!(
+        unsafe { SliceRef::<u32>::with_unbound(&[], |slice| slice.len()) },
+        0
+    );
+}
+
+#[test]
+fn test_slice_to_array() {
+    let header = ByteSliceRef::new(b"RIFF");
+    assert_eq!(header.to_array::<4>(), Some(*b"RIFF"));
+    assert_eq!(header.to_array::<3>(), None);
+    assert_eq!(header.to_array::<5>(), None);
+    assert_eq!(SliceRef::<u8>::default().to_array::<0>(), Some([]));
+
+    let array: crate::Array<u8, 4> = header.try_into().unwrap();
+    assert_eq!(&array, b"RIFF");
+    assert!(<[u8; 2]>::try_from(header).is_err());
+
+    let boxed = BoxedSlice::from([1u32, 2, 3]);
+    assert_eq!(boxed.to_array::<3>(), Some([1, 2, 3]));
+    assert_eq!(boxed.to_array::<2>(), None);
+    assert_eq!(boxed.to_array::<4>(), None);
+}
+
+#[test]
+fn test_boxed_slice_as_box() {
+    // run under `cargo miri test` to check the casts for aliasing and provenance
+    let mut boxed = BoxedSlice::new(alloc::vec![1, 2, 3].into_boxed_slice());
+    AsMut::<[i32]>::as_mut(&mut boxed)[0] = 10;
+    assert_eq!(&**AsRef::<Box<[i32]>>::as_ref(&boxed), &[10, 2, 3]);
+
+    AsMut::<Box<[i32]>>::as_mut(&mut boxed)[1] = 20;
+    assert_eq!(&*boxed, &[10, 20, 3]);
+
+    *AsMut::<Box<[i32]>>::as_mut(&mut boxed) = alloc::vec![4].into_boxed_slice();
+    assert_eq!(boxed.len(), 1);
+    assert_eq!(&*boxed.into_boxed_slice(), &[4]);
+
+    let empty = BoxedSlice::<u64>::empty();
+    assert!(AsRef::<Box<[u64]>>::as_ref(&empty).is_empty());
+}
+
+#[test]
+fn test_slice_mixed_eq() {
+    let boxed = BoxedSlice::from([1, 2, 3]);
+    let slice = SliceRef::new(&[1, 2, 3]);
+    assert!(boxed == slice);
+    assert!(slice == boxed);
+    assert!(boxed != SliceRef::new(&[1, 2]));
+    assert!(BoxedSlice::<u8>::empty() == SliceRef::default());
+}
+
+#[test]
+fn test_slice_try_index() {
+    let slice = SliceRef::new(&[10, 20]);
+    assert_eq!(slice.try_index(1), Ok(&20));
+    let err = slice.try_index(2).unwrap_err();
+    assert_eq!((err.index, err.len), (2, 2));
+    assert_eq!(
+        alloc::format!("{}", err),
+        "index 2 is out of bounds for length 2"
+    );
+    assert!(slice.try_index(usize::MAX).is_err());
+    assert!(SliceRef::<u8>::default().try_index(0).is_err());
+
+    let boxed = BoxedSlice::from([1u8]);
+    assert_eq!(boxed.try_index(0), Ok(&1));
+    assert_eq!(boxed.try_index(1).unwrap_err().len, 1);
+}
+
+#[test]
+fn test_slice_ref_lt() {
+    extern "C" fn sum(slice: SliceRef<u32>) -> u32 {
+        slice.iter().sum()
+    }
+
+    let values = alloc::vec![1, 2, 3];
+    let staged = SliceRefLt::new(&values);
+    assert_eq!(staged.len(), 3);
+    assert_eq!(&staged[1..], &[2, 3]);
+    assert_eq!(sum(unsafe { staged.erase() }), 6);
+
+    let from_static = SliceRefLt::from(SliceRef::new(&[4u8]));
+    assert_eq!(from_static.as_slice(), &[4]);
+    assert!(SliceRefLt::<u8>::new(&[]).is_empty());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "use of a dropped BoxedSlice")]
+fn test_boxed_slice_use_after_drop() {
+    let mut boxed = core::mem::ManuallyDrop::new(BoxedSlice::from([1u32, 2, 3]));
+    unsafe { core::mem::ManuallyDrop::drop(&mut boxed) };
+    // the wrapper itself is still readable, and only the pointed buffer is freed
+    let _: &[u32] = boxed.as_ref();
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "use of a dropped BoxedSlice")]
+fn test_boxed_slice_double_drop() {
+    let mut boxed = core::mem::ManuallyDrop::new(BoxedSlice::from([1u8]));
+    unsafe {
+        core::mem::ManuallyDrop::drop(&mut boxed);
+        core::mem::ManuallyDrop::drop(&mut boxed);
+    }
+}
+
+#[test]
+fn test_boxed_slice_heap_size() {
+    assert_eq!(BoxedSlice::from([1u32, 2, 3]).heap_size(), 12);
+    assert_eq!(BoxedSlice::from([[0u8; 3]; 2]).heap_size(), 6);
+    assert_eq!(BoxedSlice::<u64>::default().heap_size(), 0);
+    assert_eq!(BoxedSlice::from([(), ()]).heap_size(), 0);
+}
+
+#[test]
+fn test_as_ptr_range() {
+    let values = SliceRef::new(&[1u32, 2, 3]);
+    let range = values.as_ptr_range();
+    assert_eq!(range.start, values.as_ptr());
+    assert_eq!(unsafe { range.end.offset_from(range.start) }, 3);
+
+    let mut boxed = BoxedSlice::from([1u16, 2]);
+    let range = boxed.as_mut_ptr_range();
+    unsafe { *range.end.sub(1) = 20 };
+    assert_eq!(boxed.as_ptr_range(), boxed.iter().as_slice().as_ptr_range());
+    assert_eq!(&boxed[..], &[1, 20]);
+
+    let mut buffer = [0u8; 4];
+    let mut mut_slice = unsafe { MutSliceRef::new_unbound(&mut buffer) };
+    let range = mut_slice.as_mut_ptr_range();
+    assert_eq!(range.end as usize - range.start as usize, 4);
+
+    // empty slices keep the aligned dangling pointer
+    let empty = SliceRef::<u64>::default().as_ptr_range();
+    assert_eq!(empty.start, empty.end);
+    assert_eq!(empty.start as usize, core::mem::align_of::<u64>());
+    let empty = BoxedSlice::<u64>::default().as_ptr_range();
+    assert_eq!(empty.start, empty.end);
+}
+
+#[test]
+fn test_mut_slice_ref_reverse_rotate() {
+    let mut buffer = [1, 2, 3, 4, 5];
+    let mut slice = unsafe { MutSliceRef::new_unbound(&mut buffer) };
+    slice.reverse();
+    slice.rotate_left(2);
+    slice.rotate_right(1);
+    // the memory seen by the caller is changed
+    assert_eq!(buffer, [4, 3, 2, 1, 5]);
+
+    let mut empty = MutSliceRef::<u8>::default();
+    empty.reverse();
+    empty.rotate_left(0);
+    empty.rotate_right(0);
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_mut_slice_ref_rotate_out_of_range() {
+    let mut buffer = [1, 2];
+    let mut slice = unsafe { MutSliceRef::new_unbound(&mut buffer) };
+    slice.rotate_left(3);
+}
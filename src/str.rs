@@ -1,4 +1,5 @@
 use crate::slice::SliceInner;
+use alloc::boxed::Box;
 
 /// Rust wrapper for &str.
 ///
@@ -9,13 +10,21 @@ use crate::slice::SliceInner;
 #[repr(C)]
 pub struct StrRef(pub(crate) SliceInner<u8>);
 static_assertions::assert_eq_size!(StrRef, &str);
+// SAFETY: same as &str
+unsafe impl Send for StrRef {}
+unsafe impl Sync for StrRef {}
 
 /// Rust wrapper for Box<str>.
 ///
 /// Since boxed types are only created from Rust side, the value is expected to be valid under safe operations.
 #[repr(C)]
 pub struct BoxedStr(SliceInner<u8>);
-static_assertions::assert_eq_size!(BoxedStr, std::boxed::Box<str>);
+static_assertions::assert_eq_size!(BoxedStr, alloc::boxed::Box<str>);
+// SAFETY: same as Box<str>
+unsafe impl Send for BoxedStr {}
+unsafe impl Sync for BoxedStr {}
+static_assertions::assert_impl_all!(StrRef: Send, Sync);
+static_assertions::assert_impl_all!(BoxedStr: Send, Sync);
 
 impl Clone for StrRef {
     #[inline(always)]
@@ -44,17 +53,226 @@ impl StrRef {
         Self::new(crate::into_static(value))
     }
 
+    /// Create a new wrapper for borrowed bytes after UTF-8 validation, e.g. bytes received from C.
+    /// `unbound` means bounded lifetime will be removed to be static.
+    ///
+    /// # Safety
+    /// The returned object must not outlive the given bytes.
+    #[inline]
+    pub unsafe fn try_new_unbound(bytes: &'_ [u8]) -> Result<Self, core::str::Utf8Error> {
+        core::str::from_utf8(bytes).map(|s| Self::new_unbound(s))
+    }
+
+    /// Runs `f` with an unbound wrapper of `value`. See [`SliceRef::with_unbound`](crate::SliceRef::with_unbound).
+    ///
+    /// # Safety
+    /// `f` must not keep the wrapper or any copy of it after returning.
+    #[inline]
+    pub unsafe fn with_unbound<R>(value: &str, f: impl FnOnce(StrRef) -> R) -> R {
+        f(Self::new_unbound(value))
+    }
+
+    /// Create a new wrapper for static bytes without UTF-8 validation.
+    /// Use [`TryFrom`] to validate the bytes.
+    ///
+    /// # Safety
+    /// `bytes` must be a valid UTF-8 string.
+    #[inline(always)]
+    pub const unsafe fn from_utf8_unchecked(bytes: &'static [u8]) -> Self {
+        Self::new(core::str::from_utf8_unchecked(bytes))
+    }
+
+    /// Create a new wrapper for static bytes in const context, e.g. for constant tables.
+    ///
+    /// The bytes are validated while evaluating the constant, so no runtime validation is left.
+    /// See [`StrRef::from_bytes`] to validate without panicking.
+    ///
+    /// # Panics
+    /// Panics if `bytes` is not a valid UTF-8 string. In const context, this is a compile error.
+    #[inline(always)]
+    pub const fn from_bytes_const(bytes: &'static [u8]) -> Self {
+        match core::str::from_utf8(bytes) {
+            Ok(s) => Self::new(s),
+            Err(_) => panic!("StrRef::from_bytes_const: invalid UTF-8"),
+        }
+    }
+
+    /// Create a new wrapper for static bytes after UTF-8 validation.
+    #[inline]
+    pub fn from_bytes(bytes: &'static [u8]) -> Result<Self, core::str::Utf8Error> {
+        core::str::from_utf8(bytes).map(Self::new)
+    }
+
+    /// Returns the str, or `None` if the pointer is null as zero-initialized by C.
+    ///
+    /// This distinguishes an absent string from an empty one: a non-null pointer with length 0 yields `Some("")`.
+    #[inline]
+    pub fn try_as_str(&self) -> Option<&str> {
+        if self.0.ptr.is_null() {
+            None
+        } else {
+            Some(self.0.checked_str())
+        }
+    }
+
     #[inline(always)]
     pub const fn as_str(&self) -> &'static str {
         self.into_str()
     }
 
     /// Inverse of [`StrRef::new`].
+    ///
+    /// An empty string gives `""` without reading the pointer, which may be null as zero-initialized by C.
+    /// Debug builds check the pointer is non-null when not empty.
     #[inline(always)]
     pub const fn into_str(self) -> &'static str {
+        if self.0.len == 0 {
+            return "";
+        }
+        debug_assert!(
+            !self.0.ptr.is_null(),
+            "str pointer is null for non-empty length"
+        );
         let union = StrUnion { inner: self.0 };
         unsafe { union.str }
     }
+
+    /// Returns the bytes as a [`ByteSliceRef`](crate::ByteSliceRef) over the same buffer without copying.
+    ///
+    /// Inverse of [`ByteSliceRef::try_into_str_ref`](crate::ByteSliceRef::try_into_str_ref).
+    #[inline(always)]
+    pub const fn as_byte_slice_ref(&self) -> crate::ByteSliceRef {
+        crate::SliceRef(self.0)
+    }
+
+    /// Returns the number of `char`s by walking the whole string, which is O(n) unlike the O(1) `len`.
+    #[inline]
+    pub fn char_count(&self) -> usize {
+        self.0.checked_str().chars().count()
+    }
+
+    #[inline]
+    pub fn is_ascii(&self) -> bool {
+        self.0.checked_str().is_ascii()
+    }
+
+    /// Returns a new wrapper over the byte `range` of the same buffer, or an error if the range is
+    /// out of bounds or not on char boundaries.
+    ///
+    /// This never panics, unlike slicing a `str`. See [`SliceRef::try_index`](crate::SliceRef::try_index).
+    #[inline]
+    pub fn try_slice(&self, range: core::ops::Range<usize>) -> Result<StrRef, crate::IndexError> {
+        let s = self.0.checked_str();
+        let error = |index| crate::IndexError {
+            index,
+            len: s.len(),
+        };
+        if !s.is_char_boundary(range.start) {
+            return Err(error(range.start));
+        }
+        s.get(range.clone())
+            .map(|s| StrRef(SliceInner::from_str(s)))
+            .ok_or(error(range.end))
+    }
+
+    /// Splits at the byte offset `mid`, or returns `None` if `mid` is not on a char boundary or out of bounds.
+    ///
+    /// Unlike [`str::split_at`], this doesn't panic for an offset from external code.
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> Option<(StrRef, StrRef)> {
+        let (head, tail) = self.0.checked_str().split_at_checked(mid)?;
+        Some((
+            StrRef(SliceInner::from_str(head)),
+            StrRef(SliceInner::from_str(tail)),
+        ))
+    }
+
+    /// Same as [`str::split`] with a char pattern, but yields [`StrRef`].
+    ///
+    /// The yielded values borrow the buffer of `self`, so they share its validity.
+    #[inline]
+    pub fn split(&self, pat: char) -> impl Iterator<Item = StrRef> {
+        self.into_str().split(pat).map(Self::new)
+    }
+
+    /// Same as [`str::lines`], but yields [`StrRef`].
+    ///
+    /// The yielded values borrow the buffer of `self`, so they share its validity.
+    #[inline]
+    pub fn lines(&self) -> impl Iterator<Item = StrRef> {
+        self.into_str().lines().map(Self::new)
+    }
+
+    /// Same as [`str::rsplit`] with a char pattern, but yields [`StrRef`].
+    ///
+    /// The yielded values borrow the buffer of `self`, so they share its validity.
+    #[inline]
+    pub fn rsplit(&self, pat: char) -> impl Iterator<Item = StrRef> {
+        self.into_str().rsplit(pat).map(Self::new)
+    }
+
+    /// Same as [`str::trim`], but returns [`StrRef`] over the same buffer.
+    ///
+    /// An all-whitespace string results in an empty [`StrRef`] pointing into the buffer.
+    #[inline]
+    pub fn trim(&self) -> StrRef {
+        Self::new(self.0.checked_str().trim())
+    }
+
+    /// Same as [`str::trim_start`], but returns [`StrRef`] over the same buffer. See [`StrRef::trim`].
+    #[inline]
+    pub fn trim_start(&self) -> StrRef {
+        Self::new(self.0.checked_str().trim_start())
+    }
+
+    /// Same as [`str::trim_end`], but returns [`StrRef`] over the same buffer. See [`StrRef::trim`].
+    #[inline]
+    pub fn trim_end(&self) -> StrRef {
+        Self::new(self.0.checked_str().trim_end())
+    }
+
+    /// Same as [`str::starts_with`] for a `&str` prefix.
+    /// The stored length is checked first, so an empty string never reads its pointer.
+    #[inline]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.0.checked_str().starts_with(prefix)
+    }
+
+    /// Same as [`str::ends_with`] for a `&str` suffix. See [`StrRef::starts_with`].
+    #[inline]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.0.checked_str().ends_with(suffix)
+    }
+}
+
+impl PartialEq for StrRef {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.checked_str() == other.0.checked_str()
+    }
+}
+
+impl Eq for StrRef {}
+
+impl PartialOrd for StrRef {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StrRef {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.checked_str().cmp(other.0.checked_str())
+    }
+}
+
+impl Default for StrRef {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new("")
+    }
 }
 
 impl From<&'static str> for StrRef {
@@ -64,6 +282,15 @@ impl From<&'static str> for StrRef {
     }
 }
 
+impl TryFrom<&'static [u8]> for StrRef {
+    type Error = core::str::Utf8Error;
+
+    #[inline]
+    fn try_from(bytes: &'static [u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
 impl From<StrRef> for &'static str {
     #[inline(always)]
     fn from(s: StrRef) -> Self {
@@ -71,21 +298,21 @@ impl From<StrRef> for &'static str {
     }
 }
 
-impl std::convert::AsRef<str> for StrRef {
+impl core::convert::AsRef<str> for StrRef {
     #[inline(always)]
     fn as_ref(&self) -> &'static str {
         self.into_str()
     }
 }
 
-impl std::borrow::Borrow<str> for StrRef {
+impl core::borrow::Borrow<str> for StrRef {
     #[inline(always)]
     fn borrow(&self) -> &str {
         self.as_ref()
     }
 }
 
-impl std::ops::Deref for StrRef {
+impl core::ops::Deref for StrRef {
     type Target = str;
 
     #[inline(always)]
@@ -94,39 +321,328 @@ impl std::ops::Deref for StrRef {
     }
 }
 
+/// Concatenates into a new [`BoxedStr`].
+///
+/// Unlike `String + &str`, which appends in place, the result is always a fresh allocation and `self` is untouched.
+impl core::ops::Add<&str> for StrRef {
+    type Output = BoxedStr;
+
+    #[inline]
+    fn add(self, rhs: &str) -> Self::Output {
+        BoxedStr::new([self.as_ref(), rhs].concat().into_boxed_str())
+    }
+}
+
+/// Same as `Add<&str>`; the result is always a fresh allocation.
+impl core::ops::Add<StrRef> for StrRef {
+    type Output = BoxedStr;
+
+    #[inline]
+    fn add(self, rhs: StrRef) -> Self::Output {
+        self + rhs.as_ref()
+    }
+}
+
+/// Lifetime-bound counterpart of [`StrRef`]. See [`SliceRefLt`](crate::SliceRefLt).
+#[repr(transparent)]
+pub struct StrRefLt<'a>(SliceInner<u8>, core::marker::PhantomData<&'a str>);
+static_assertions::assert_eq_size!(StrRefLt<'static>, StrRef);
+// SAFETY: same as &str
+unsafe impl Send for StrRefLt<'_> {}
+unsafe impl Sync for StrRefLt<'_> {}
+
+impl Clone for StrRefLt<'_> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for StrRefLt<'_> {}
+
+impl<'a> StrRefLt<'a> {
+    #[inline(always)]
+    pub const fn new(value: &'a str) -> Self {
+        Self(SliceInner::from_str(value), core::marker::PhantomData)
+    }
+
+    #[inline(always)]
+    pub fn as_str(&self) -> &'a str {
+        self.0.checked_str()
+    }
+
+    /// Converts into [`StrRef`] without the lifetime, to hand it across the boundary. The layout is not changed.
+    ///
+    /// # Safety
+    /// The returned object must not outlive `'a`, the same as [`StrRef::new_unbound`].
+    #[inline(always)]
+    pub unsafe fn erase(self) -> StrRef {
+        StrRef(self.0)
+    }
+}
+
+impl<'a> From<&'a str> for StrRefLt<'a> {
+    #[inline(always)]
+    fn from(value: &'a str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<StrRef> for StrRefLt<'_> {
+    #[inline(always)]
+    fn from(value: StrRef) -> Self {
+        Self(value.0, core::marker::PhantomData)
+    }
+}
+
+impl core::ops::Deref for StrRefLt<'_> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
 impl BoxedStr {
     /// Create a new wrapper for a `Box<str>`.
+    ///
+    /// An empty `Box<str>` keeps its dangling pointer, which is non-null and trivially aligned for `u8`.
+    /// Debug builds check both, the same as [`BoxedSlice::new`](crate::BoxedSlice::new).
     #[inline(always)]
     pub fn new(value: Box<str>) -> Self {
         let inner = SliceInner::from_str(&value);
         let raw = Box::into_raw(value);
         assert_eq!(inner.ptr, raw as *mut _);
+        debug_assert!(!inner.ptr.is_null(), "boxed str pointer is null");
+        debug_assert!(inner.ptr.is_aligned(), "boxed str pointer is not aligned");
         Self(inner)
     }
 
+    /// Consumes the string and splits it into two newly allocated strings at the byte offset `mid`.
+    /// See [`StrRef::split_at`].
+    ///
+    /// Gives back the string if `mid` is not on a char boundary or out of bounds.
+    #[inline]
+    pub fn into_split_at(self, mid: usize) -> Result<(BoxedStr, BoxedStr), BoxedStr> {
+        match self.0.checked_str().split_at_checked(mid) {
+            Some((head, tail)) => Ok((Self::new(head.into()), Self::new(tail.into()))),
+            None => Err(self),
+        }
+    }
+
+    /// Leaks the allocation and returns a borrowed wrapper of it, like [`Box::leak`].
+    ///
+    /// The buffer is intentionally never freed, so the returned [`StrRef`] is valid for `'static`.
+    #[inline(always)]
+    pub fn leak(self) -> StrRef {
+        StrRef(core::mem::ManuallyDrop::new(self).0)
+    }
+
+    /// Create an empty boxed str without allocation.
+    #[inline(always)]
+    pub const fn empty() -> Self {
+        Self(SliceInner::empty())
+    }
+
+    /// Create a new boxed str by formatting `args`. See also [`boxed_str!`](crate::boxed_str).
+    ///
+    /// An empty output results in [`BoxedStr::empty`].
+    #[inline]
+    pub fn from_fmt(args: core::fmt::Arguments<'_>) -> Self {
+        let string = alloc::fmt::format(args);
+        if string.is_empty() {
+            return Self::empty();
+        }
+        Self::new(string.into_boxed_str())
+    }
+
+    /// Create a new boxed str from the [`Display`](core::fmt::Display) output of `value`.
+    #[inline]
+    pub fn from_display(value: &impl core::fmt::Display) -> Self {
+        Self::from_fmt(format_args!("{}", value))
+    }
+
+    /// Create a new wrapper from boxed bytes after UTF-8 validation.
+    ///
+    /// The buffer is reused without copying. On error, the bytes are dropped.
+    #[inline]
+    pub fn from_utf8(bytes: Box<[u8]>) -> Result<Self, core::str::Utf8Error> {
+        core::str::from_utf8(&bytes)?;
+        Ok(unsafe { Self::from_utf8_unchecked(bytes) })
+    }
+
+    /// Create a new wrapper from boxed bytes without UTF-8 validation.
+    ///
+    /// # Safety
+    /// `bytes` must be a valid UTF-8 string.
+    #[inline(always)]
+    pub unsafe fn from_utf8_unchecked(bytes: Box<[u8]>) -> Self {
+        Self::new(alloc::str::from_boxed_utf8_unchecked(bytes))
+    }
+
+    /// Create a new wrapper from a boxed byte slice after UTF-8 validation.
+    ///
+    /// The ownership of the buffer is moved without reallocation. On error, the bytes are dropped.
+    #[inline]
+    pub fn from_boxed_slice(bytes: crate::BoxedSlice<u8>) -> Result<Self, core::str::Utf8Error> {
+        core::str::from_utf8(&bytes)?;
+        let bytes = core::mem::ManuallyDrop::new(bytes);
+        Ok(Self(bytes.0))
+    }
+
+    /// Inverse of [`BoxedStr::from_boxed_slice`].
+    #[inline(always)]
+    pub fn into_boxed_bytes(self) -> crate::BoxedSlice<u8> {
+        let this = core::mem::ManuallyDrop::new(self);
+        crate::BoxedSlice(this.0)
+    }
+
     /// Inverse of [`BoxedStr::new`].
     #[inline(always)]
     pub fn into_boxed_str(self) -> Box<str> {
+        self.0.debug_assert_valid();
+        let union = core::mem::ManuallyDrop::new(self).0.str_union();
+        core::mem::ManuallyDrop::into_inner(unsafe { union.boxed })
+    }
+
+    /// Writes a little-endian `u32` length followed by the bytes into `out`, and returns the bytes written.
+    /// See [`BoxedSlice::write_length_prefixed`](crate::BoxedSlice::write_length_prefixed), and
+    /// [`BoxedSlice::read_length_prefixed`](crate::BoxedSlice::read_length_prefixed) to read it back.
+    ///
+    /// # Panics
+    /// Panics if the length exceeds `u32::MAX`.
+    #[inline]
+    pub fn write_length_prefixed(
+        &self,
+        out: &mut crate::MutSliceRef<u8>,
+    ) -> Result<usize, crate::BufferTooSmall> {
+        crate::byte_slice::write_length_prefixed(self.as_bytes(), out)
+    }
+
+    /// Returns the bytes held on the heap, which is the length in bytes. Only `len` is read.
+    #[inline(always)]
+    pub const fn heap_size(&self) -> usize {
+        self.0.len
+    }
+}
+
+impl Drop for BoxedStr {
+    #[inline(always)]
+    fn drop(&mut self) {
         let union = self.0.str_union();
-        std::mem::ManuallyDrop::into_inner(unsafe { union.boxed })
+        let boxed: Box<str> = core::mem::ManuallyDrop::into_inner(unsafe { union.boxed });
+        drop(boxed);
+    }
+}
+
+impl PartialEq for BoxedStr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.checked_str() == other.0.checked_str()
+    }
+}
+
+impl Eq for BoxedStr {}
+
+impl PartialEq<StrRef> for BoxedStr {
+    #[inline]
+    fn eq(&self, other: &StrRef) -> bool {
+        self.0.checked_str() == other.0.checked_str()
+    }
+}
+
+impl PartialEq<BoxedStr> for StrRef {
+    #[inline]
+    fn eq(&self, other: &BoxedStr) -> bool {
+        self.0.checked_str() == other.0.checked_str()
+    }
+}
+
+impl PartialOrd for BoxedStr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
-impl From<std::boxed::Box<str>> for BoxedStr {
+impl Ord for BoxedStr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.checked_str().cmp(other.0.checked_str())
+    }
+}
+
+impl Default for BoxedStr {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl From<alloc::boxed::Box<str>> for BoxedStr {
     #[inline(always)]
-    fn from(value: std::boxed::Box<str>) -> Self {
+    fn from(value: alloc::boxed::Box<str>) -> Self {
         Self::new(value)
     }
 }
 
-impl From<BoxedStr> for std::boxed::Box<str> {
+impl From<BoxedStr> for alloc::boxed::Box<str> {
     #[inline(always)]
     fn from(value: BoxedStr) -> Self {
         value.into_boxed_str()
     }
 }
 
-impl std::convert::AsRef<str> for BoxedStr {
+/// Always results in an owned string; a borrowed value is copied.
+///
+/// To avoid the copy, match the value and wrap a borrowed `&'static str` by [`StrRef::new`] instead.
+impl From<alloc::borrow::Cow<'static, str>> for BoxedStr {
+    #[inline]
+    fn from(s: alloc::borrow::Cow<'static, str>) -> Self {
+        Self::new(s.into_owned().into_boxed_str())
+    }
+}
+
+/// Builds a `String` once, then converts it by [`String::into_boxed_str`](alloc::string::String::into_boxed_str).
+impl FromIterator<char> for BoxedStr {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        Self::new(alloc::string::String::from_iter(iter).into_boxed_str())
+    }
+}
+
+impl<'a> FromIterator<&'a str> for BoxedStr {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        Self::new(alloc::string::String::from_iter(iter).into_boxed_str())
+    }
+}
+
+impl FromIterator<alloc::string::String> for BoxedStr {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = alloc::string::String>>(iter: I) -> Self {
+        Self::new(alloc::string::String::from_iter(iter).into_boxed_str())
+    }
+}
+
+impl TryFrom<crate::BoxedSlice<u8>> for BoxedStr {
+    type Error = core::str::Utf8Error;
+
+    #[inline]
+    fn try_from(value: crate::BoxedSlice<u8>) -> Result<Self, Self::Error> {
+        Self::from_boxed_slice(value)
+    }
+}
+
+impl From<BoxedStr> for crate::BoxedSlice<u8> {
+    #[inline(always)]
+    fn from(value: BoxedStr) -> Self {
+        value.into_boxed_bytes()
+    }
+}
+
+impl core::convert::AsRef<str> for BoxedStr {
     #[inline(always)]
     fn as_ref(&self) -> &str {
         let union = self.0.str_union();
@@ -134,21 +650,21 @@ impl std::convert::AsRef<str> for BoxedStr {
     }
 }
 
-impl std::convert::AsRef<Box<str>> for BoxedStr {
+impl core::convert::AsRef<Box<str>> for BoxedStr {
     #[inline(always)]
     fn as_ref(&self) -> &Box<str> {
         unsafe { &*(&self.0 as *const SliceInner<u8> as *const Box<str>) }
     }
 }
 
-impl std::borrow::Borrow<str> for BoxedStr {
+impl core::borrow::Borrow<str> for BoxedStr {
     #[inline(always)]
     fn borrow(&self) -> &str {
         self.as_ref()
     }
 }
 
-impl std::ops::Deref for BoxedStr {
+impl core::ops::Deref for BoxedStr {
     type Target = str;
 
     #[inline(always)]
@@ -160,10 +676,71 @@ impl std::ops::Deref for BoxedStr {
 pub(crate) union StrUnion {
     inner: SliceInner<u8>,
     str: &'static str,
-    boxed: std::mem::ManuallyDrop<std::boxed::Box<str>>,
+    boxed: core::mem::ManuallyDrop<alloc::boxed::Box<str>>,
 }
 static_assertions::assert_eq_size!(SliceInner<u8>, StrUnion);
 
+/// Builds a [`BoxedStr`] incrementally, e.g. for a string returned to the other side.
+///
+/// The intermediate `String` is kept private and converted once by [`BoxedStrBuilder::build`],
+/// which shrinks the buffer to the exact length like [`String::into_boxed_str`](alloc::string::String::into_boxed_str).
+#[derive(Default)]
+pub struct BoxedStrBuilder {
+    buffer: alloc::string::String,
+}
+
+impl BoxedStrBuilder {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reserves `capacity` bytes up front.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: alloc::string::String::with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    pub fn push_str(&mut self, s: &str) -> &mut Self {
+        self.buffer.push_str(s);
+        self
+    }
+
+    #[inline]
+    pub fn push(&mut self, c: char) -> &mut Self {
+        self.buffer.push(c);
+        self
+    }
+
+    /// Returns the length in bytes so far.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// An empty builder results in an empty [`BoxedStr`], the same as [`BoxedStr::default`].
+    #[inline]
+    pub fn build(self) -> BoxedStr {
+        BoxedStr::new(self.buffer.into_boxed_str())
+    }
+}
+
+impl core::fmt::Write for BoxedStrBuilder {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
 impl SliceInner<u8> {
     #[inline(always)]
     pub const fn from_str(value: &str) -> Self {
@@ -175,4 +752,437 @@ impl SliceInner<u8> {
     pub const fn str_union(self) -> StrUnion {
         StrUnion { inner: self }
     }
+
+    /// Returns the str without reading `ptr` when `len` is 0.
+    ///
+    /// The bytes must be a valid UTF-8 string, as [`StrRef`] and [`BoxedStr`] guarantee.
+    #[inline(always)]
+    pub(crate) fn checked_str<'a>(self) -> &'a str {
+        unsafe { core::str::from_utf8_unchecked(self.checked_slice()) }
+    }
+}
+
+/// Creates a [`BoxedStr`] like [`alloc::format!`].
+///
+/// ```
+/// let message = ffi_types::boxed_str!("{} items", 3);
+/// assert_eq!(&*message, "3 items");
+/// ```
+#[macro_export]
+macro_rules! boxed_str {
+    ($($arg:tt)*) => {
+        $crate::BoxedStr::from_fmt(::core::format_args!($($arg)*))
+    };
+}
+
+#[test]
+fn test_boxed_str_ownership() {
+    let boxed = BoxedStr::new(Box::from("owned")).into_boxed_str();
+    assert_eq!(&*boxed, "owned");
+    // freed by the wrapper instead of leaking
+    drop(BoxedStr::new(Box::from("dropped")));
+}
+
+#[test]
+fn test_boxed_str_from_utf8() {
+    let boxed = BoxedStr::from_utf8(b"hello".to_vec().into_boxed_slice()).unwrap();
+    assert_eq!(&*boxed, "hello");
+
+    let empty = BoxedStr::from_utf8(Box::new([])).unwrap();
+    assert_eq!(&*empty, "");
+
+    let err = BoxedStr::from_utf8(Box::new([b'a', 0xff])).err().unwrap();
+    assert_eq!(err.valid_up_to(), 1);
+}
+
+#[test]
+fn test_str_default() {
+    assert_eq!(StrRef::default().as_str(), "");
+
+    // ensure dropping default boxed str is no-op
+    let boxed = BoxedStr::default();
+    assert_eq!(&*boxed, "");
+    drop(boxed);
+}
+
+#[test]
+fn test_boxed_str_from_boxed_slice() {
+    let bytes = crate::BoxedSlice::new(b"hello".to_vec().into_boxed_slice());
+    let ptr = bytes.as_ptr();
+    let boxed = BoxedStr::from_boxed_slice(bytes).unwrap();
+    assert_eq!(&*boxed, "hello");
+    assert_eq!(boxed.as_ptr(), ptr);
+
+    let bytes = boxed.into_boxed_bytes();
+    assert_eq!(&*bytes, b"hello");
+    assert_eq!(bytes.as_ptr(), ptr);
+
+    let invalid = crate::BoxedSlice::new(Box::new([0xe3, 0x81]));
+    assert_eq!(BoxedStr::try_from(invalid).err().unwrap().error_len(), None);
+
+    let empty = BoxedStr::from_boxed_slice(crate::BoxedSlice::empty()).unwrap();
+    assert_eq!(&*empty, "");
+    assert!(crate::BoxedSlice::from(empty).is_empty());
+}
+
+#[test]
+fn test_str_try_from_bytes() {
+    let s = StrRef::try_from("안녕".as_bytes()).unwrap();
+    assert_eq!(s.as_str(), "안녕");
+    assert_eq!(s.len(), 6);
+
+    let err = StrRef::try_from(&b"ab\xed\xa0\x80"[..]).err().unwrap();
+    assert_eq!(err.valid_up_to(), 2);
+    assert_eq!(err.error_len(), Some(1));
+
+    let s = unsafe { StrRef::from_utf8_unchecked(b"hello") };
+    assert_eq!(s.as_str(), "hello");
+}
+
+#[test]
+fn test_str_split() {
+    let s = StrRef::new("a,b,,c");
+    let parts: alloc::vec::Vec<_> = s.split(',').map(StrRef::into_str).collect();
+    assert_eq!(parts, ["a", "b", "", "c"]);
+
+    let s = StrRef::new("first\nsecond\r\n\nlast\n");
+    let lines: alloc::vec::Vec<_> = s.lines().map(StrRef::into_str).collect();
+    assert_eq!(lines, ["first", "second", "", "last"]);
+    assert_eq!(StrRef::new("").lines().count(), 0);
+}
+
+#[test]
+fn test_str_ord() {
+    assert!(StrRef::new("") < StrRef::new("a"));
+    assert!(StrRef::new("a") < StrRef::new("ab"));
+    assert!(StrRef::new("a") == StrRef::new("a"));
+    assert!(BoxedStr::empty() == BoxedStr::new("".into()));
+
+    let mut strings: alloc::vec::Vec<BoxedStr> = ["pear", "", "apple", "apples", "Zoo"]
+        .iter()
+        .map(|s| BoxedStr::new((*s).into()))
+        .collect();
+    strings.sort();
+    assert!(strings
+        .iter()
+        .map(|s| &**s)
+        .eq(["", "Zoo", "apple", "apples", "pear"]));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "str pointer is null for non-empty length")]
+fn test_into_str_null() {
+    let null = StrRef(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 1,
+    });
+    let _ = null.into_str();
+}
+
+#[test]
+fn test_str_from_bytes_const() {
+    const MESSAGES: [StrRef; 2] = [
+        StrRef::from_bytes_const(b"not found"),
+        StrRef::from_bytes_const(b"\xEC\x97\x86\xEC\x9D\x8C"),
+    ];
+    assert_eq!(MESSAGES[0].as_str(), "not found");
+    assert_eq!(MESSAGES[1].as_str(), "\u{C5C6}\u{C74C}");
+
+    assert_eq!(StrRef::from_bytes(b"ok").unwrap().as_str(), "ok");
+    assert!(StrRef::from_bytes(b"\xff").is_err());
+}
+
+#[test]
+#[should_panic(expected = "invalid UTF-8")]
+fn test_str_from_bytes_const_invalid() {
+    let bytes: &'static [u8] = b"\xff";
+    let _ = StrRef::from_bytes_const(core::hint::black_box(bytes));
+}
+
+#[test]
+fn test_boxed_str_from_fmt() {
+    let code = 404;
+    assert_eq!(
+        &*boxed_str!("error {code}: {}", "not found"),
+        "error 404: not found"
+    );
+    assert_eq!(&*BoxedStr::from_display(&1.5), "1.5");
+
+    let empty = boxed_str!("{}", "");
+    assert!(empty.is_empty());
+    assert_eq!(empty.as_ptr(), BoxedStr::empty().as_ptr());
+}
+
+#[test]
+fn test_boxed_str_leak() {
+    let leaked: &'static str = BoxedStr::new("cached".into()).leak().into_str();
+    assert_eq!(leaked, "cached");
+    // give back the allocation not to be reported by leak checkers
+    drop(unsafe { alloc::boxed::Box::from_raw(leaked as *const str as *mut str) });
+}
+
+#[test]
+fn test_str_try_as_str() {
+    assert_eq!(StrRef::new("name").try_as_str(), Some("name"));
+    assert_eq!(StrRef::new("").try_as_str(), Some(""));
+
+    // zero-initialized by C side
+    let null = StrRef(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    });
+    assert_eq!(null.try_as_str(), None);
+}
+
+#[test]
+fn test_boxed_str_from_cow() {
+    use alloc::borrow::Cow;
+    assert_eq!(&*BoxedStr::from(Cow::Borrowed("borrowed")), "borrowed");
+    let owned: Cow<'static, str> = Cow::Owned("owned".into());
+    assert_eq!(&*BoxedStr::from(owned), "owned");
+}
+
+#[test]
+fn test_str_split_at() {
+    let s = StrRef::new("a\u{AC00}b");
+    let (head, tail) = s.split_at(4).unwrap();
+    assert_eq!(head.as_str(), "a\u{AC00}");
+    assert_eq!(tail.as_str(), "b");
+    assert!(s.split_at(2).is_none());
+    assert!(s.split_at(6).is_none());
+    assert!(s.split_at(5).unwrap().1.is_empty());
+
+    let boxed = BoxedStr::new("\u{AC00}\u{B098}".into());
+    let Err(boxed) = boxed.into_split_at(1) else {
+        panic!("mid-codepoint offset must fail");
+    };
+    let (head, tail) = boxed.into_split_at(3).ok().unwrap();
+    assert_eq!(&*head, "\u{AC00}");
+    assert_eq!(&*tail, "\u{B098}");
+}
+
+#[test]
+fn test_str_char_count() {
+    let s = StrRef::new("a\u{AC00}b");
+    assert_eq!(s.len(), 5);
+    assert_eq!(s.char_count(), 3);
+    assert!(!s.is_ascii());
+    assert!(StrRef::new("abc").is_ascii());
+
+    // zero-initialized by C side
+    let null = StrRef(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    });
+    assert_eq!(null.char_count(), 0);
+    assert!(null.is_ascii());
+}
+
+#[test]
+fn test_str_trim_rsplit() {
+    let s = StrRef::new("  key = value \n");
+    assert_eq!(s.trim().as_str(), "key = value");
+    assert_eq!(s.trim_start().as_str(), "key = value \n");
+    assert_eq!(s.trim_end().as_str(), "  key = value");
+
+    let blank = StrRef::new(" \t\n");
+    let trimmed = blank.trim();
+    assert!(trimmed.is_empty());
+    assert!(!trimmed.0.ptr.is_null());
+
+    let path = StrRef::new("a/b/c");
+    let parts: alloc::vec::Vec<_> = path.rsplit('/').map(|s| s.as_str()).collect();
+    assert_eq!(parts, ["c", "b", "a"]);
+}
+
+#[test]
+fn test_empty_boxed_str_roundtrip() {
+    // run under `cargo miri test` to check the empty pointer is never invalid
+    struct Holder {
+        name: BoxedStr,
+    }
+
+    let holder = Holder {
+        name: BoxedStr::new("".into()),
+    };
+    assert_eq!(&*holder.name, "");
+    assert!(!holder.name.0.ptr.is_null());
+    drop(holder);
+
+    let boxed = BoxedStr::new(alloc::string::String::new().into_boxed_str());
+    assert_eq!(&*boxed.into_boxed_str(), "");
+    assert_eq!(&*BoxedStr::empty().into_boxed_str(), "");
+}
+
+#[test]
+fn test_boxed_str_from_iter() {
+    let upper: BoxedStr = "hello".chars().map(|c| c.to_ascii_uppercase()).collect();
+    assert_eq!(&*upper, "HELLO");
+
+    let joined: BoxedStr = ["foo", "::", "bar"].into_iter().collect();
+    assert_eq!(&*joined, "foo::bar");
+
+    let owned: BoxedStr = (1..=3).map(|i| alloc::format!("{}", i)).collect();
+    assert_eq!(&*owned, "123");
+
+    let empty: BoxedStr = core::iter::empty::<char>().collect();
+    assert_eq!(&*empty, "");
+    assert!(!empty.0.ptr.is_null());
+    drop(empty);
+    drop(core::iter::empty::<&str>().collect::<BoxedStr>());
+}
+
+#[test]
+fn test_str_with_unbound() {
+    let name = alloc::format!("{}-{}", "user", 42);
+    let len = unsafe { StrRef::with_unbound(&name, |s| s.char_count()) };
+    assert_eq!(len, 7);
+}
+
+#[test]
+fn test_str_byte_slice_ref_roundtrip() {
+    let bytes = StrRef::new("héllo").as_byte_slice_ref();
+    assert_eq!(&*bytes, "héllo".as_bytes());
+    assert!(bytes.try_into_str_ref().ok() == Some(StrRef::new("héllo")));
+
+    let Err(err) = crate::ByteSliceRef::new(b"ab\xffc").try_into_str_ref() else {
+        panic!("invalid UTF-8 must fail");
+    };
+    assert_eq!(err.valid_up_to(), 2);
+
+    let empty = crate::ByteSliceRef::new(&[]).try_into_str_ref();
+    assert!(matches!(empty, Ok(s) if s.is_empty()));
+    assert!(StrRef::default().as_byte_slice_ref().is_empty());
+}
+
+#[test]
+fn test_str_mixed_eq() {
+    let boxed = BoxedStr::new("name".into());
+    assert!(boxed == StrRef::new("name"));
+    assert!(StrRef::new("name") == boxed);
+    assert!(boxed != StrRef::new("other"));
+    assert!(BoxedStr::empty() == StrRef::default());
+}
+
+#[test]
+fn test_str_try_new_unbound() {
+    let buffer = alloc::vec::Vec::from(&b"from C"[..]);
+    let s = unsafe { StrRef::try_new_unbound(&buffer) }.ok().unwrap();
+    assert_eq!(s.as_str(), "from C");
+
+    let invalid = alloc::vec![b'a', 0xc3];
+    let Err(err) = (unsafe { StrRef::try_new_unbound(&invalid) }) else {
+        panic!("invalid UTF-8 must fail");
+    };
+    assert_eq!(err.valid_up_to(), 1);
+    assert!(unsafe { StrRef::try_new_unbound(&[]) }.is_ok_and(|s| s.is_empty()));
+}
+
+#[test]
+fn test_str_try_slice() {
+    let s = StrRef::new("héllo");
+    assert_eq!(s.try_slice(0..1).unwrap().as_str(), "h");
+    assert_eq!(s.try_slice(3..6).unwrap().as_str(), "llo");
+    assert_eq!(s.try_slice(6..6).unwrap().as_str(), "");
+
+    let err = s.try_slice(0..2).err().unwrap();
+    assert_eq!((err.index, err.len), (2, 6));
+    assert_eq!(s.try_slice(2..3).err().unwrap().index, 2);
+    assert_eq!(s.try_slice(4..10).err().unwrap().index, 10);
+    assert_eq!(s.try_slice(10..12).err().unwrap().index, 10);
+    let (start, end) = (4, 3);
+    assert_eq!(s.try_slice(start..end).err().unwrap().index, 3);
+    assert!(StrRef::default().try_slice(0..0).is_ok());
+}
+
+#[test]
+fn test_str_ref_lt() {
+    let name = alloc::format!("{}!", "hello");
+    let staged = StrRefLt::new(&name);
+    assert_eq!(&*staged, "hello!");
+    assert!(staged.starts_with("he"));
+    let erased = unsafe { staged.erase() };
+    assert_eq!(erased.char_count(), 6);
+    assert_eq!(StrRefLt::from(StrRef::new("static")).as_str(), "static");
+}
+
+#[test]
+fn test_str_ref_add() {
+    let greeting = StrRef::new("hello, ");
+    let name = StrRef::new("world");
+    let joined = greeting + name;
+    assert_eq!(&*joined, "hello, world");
+    assert_eq!(&*greeting, "hello, ");
+    assert_eq!(&*(name + "!"), "world!");
+
+    let empty = StrRef::default();
+    assert_eq!(&*(empty + name), "world");
+    assert_eq!(&*(name + empty), "world");
+    assert!((empty + empty).is_empty());
+    assert!((empty + "").is_empty());
+}
+
+#[test]
+fn test_boxed_str_builder() {
+    use core::fmt::Write;
+
+    let mut builder = BoxedStrBuilder::with_capacity(16);
+    builder.push_str("id").push('=');
+    write!(builder, "{}", 42).unwrap();
+    assert_eq!(builder.len(), 5);
+    let built = builder.build();
+    assert_eq!(&*built, "id=42");
+    assert_eq!(built.len(), 5);
+
+    let empty = BoxedStrBuilder::new();
+    assert!(empty.is_empty());
+    let empty = empty.build();
+    assert!(empty.is_empty());
+    assert!(empty == BoxedStr::default());
+    assert_eq!(&*empty.into_boxed_str(), "");
+}
+
+#[test]
+fn test_boxed_str_heap_size() {
+    assert_eq!(BoxedStr::from(Box::<str>::from("héllo")).heap_size(), 6);
+    assert_eq!(BoxedStr::default().heap_size(), 0);
+}
+
+#[test]
+fn test_boxed_str_write_length_prefixed() {
+    let s = BoxedStr::from(Box::<str>::from("hé"));
+    let mut buffer = [0u8; 8];
+    let mut out = unsafe { crate::MutSliceRef::new_unbound(&mut buffer) };
+    assert_eq!(s.write_length_prefixed(&mut out), Ok(7));
+    let input = unsafe { crate::ByteSliceRef::new_unbound(&buffer) };
+    let (bytes, _) = crate::BoxedSlice::read_length_prefixed(&input).unwrap();
+    assert!(BoxedStr::from_boxed_slice(bytes).unwrap() == s);
+}
+
+#[test]
+fn test_str_ref_starts_ends_with() {
+    let s = StrRef::new("GET /index");
+    assert!(s.starts_with("GET "));
+    assert!(s.ends_with("index"));
+    assert!(s.starts_with("GET /index") && s.ends_with("GET /index"));
+    assert!(s.starts_with("") && s.ends_with(""));
+    assert!(!s.starts_with("POST"));
+    assert!(!s.ends_with("GET /index!"));
+
+    let empty = StrRef::default();
+    assert!(empty.starts_with("") && empty.ends_with(""));
+    assert!(!empty.starts_with("a") && !empty.ends_with("a"));
+}
+
+#[test]
+fn test_null_str_ref() {
+    // zero-initialized by C side
+    let null = StrRef(SliceInner {
+        ptr: core::ptr::null_mut(),
+        len: 0,
+    });
+    assert_eq!(null.into_str(), "");
+    assert!(null.is_empty());
+    assert_eq!(null.split(',').count(), 1);
 }
@@ -0,0 +1,217 @@
+use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
+
+/// Rust wrapper for Vec<T>.
+///
+/// The layout is the raw parts of `Vec<T>`: `{ ptr, len, cap }`.
+/// Unlike [`crate::BoxedSlice`], the capacity is kept to allow growing the buffer from the other side through Rust.
+#[repr(C)]
+pub struct FfiVec<T: 'static> {
+    ptr: *mut T,
+    len: usize,
+    cap: usize,
+}
+static_assertions::assert_eq_size!(FfiVec<u8>, Vec<u8>);
+// SAFETY: same as Vec<T>
+unsafe impl<T: Send> Send for FfiVec<T> {}
+unsafe impl<T: Sync> Sync for FfiVec<T> {}
+static_assertions::assert_impl_all!(FfiVec<u8>: Send, Sync);
+static_assertions::assert_not_impl_any!(FfiVec<alloc::rc::Rc<u8>>: Send, Sync);
+
+impl<T> FfiVec<T> {
+    /// Create a new wrapper for a `Vec<T>`.
+    #[inline]
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let mut vec = ManuallyDrop::new(vec);
+        Self {
+            ptr: vec.as_mut_ptr(),
+            len: vec.len(),
+            cap: vec.capacity(),
+        }
+    }
+
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_vec(Vec::new())
+    }
+
+    /// Inverse of [`FfiVec::from_vec`].
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: raw parts are taken from a valid `Vec<T>`
+        unsafe { Vec::from_raw_parts(this.ptr, this.len, this.cap) }
+    }
+
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    /// Appends an element. The buffer is reallocated by the Rust allocator when it is full.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.with_vec(|vec| vec.push(value))
+    }
+
+    /// Run `f` over the reconstructed `Vec<T>` and store back its raw parts.
+    ///
+    /// `self` is left empty while `f` runs, so a panic in `f` never leaves a freed buffer behind.
+    #[inline]
+    pub(crate) fn with_vec<R>(&mut self, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+        let mut vec = core::mem::take(self).into_vec();
+        let result = f(&mut vec);
+        *self = Self::from_vec(vec);
+        result
+    }
+}
+
+impl<T> Drop for FfiVec<T> {
+    #[inline]
+    fn drop(&mut self) {
+        drop(unsafe { Vec::from_raw_parts(self.ptr, self.len, self.cap) });
+    }
+}
+
+impl<T> Default for FfiVec<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Same as `Vec::extend`, which reserves by the size hint of the iterator.
+impl<T> Extend<T> for FfiVec<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.with_vec(|vec| vec.extend(iter))
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for FfiVec<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.with_vec(|vec| vec.extend(iter))
+    }
+}
+
+impl<T> From<Vec<T>> for FfiVec<T> {
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        Self::from_vec(vec)
+    }
+}
+
+impl<T> From<FfiVec<T>> for Vec<T> {
+    #[inline]
+    fn from(vec: FfiVec<T>) -> Self {
+        vec.into_vec()
+    }
+}
+
+impl<T> From<FfiVec<T>> for crate::BoxedSlice<T> {
+    #[inline]
+    fn from(vec: FfiVec<T>) -> Self {
+        Self::new(vec.into_vec().into_boxed_slice())
+    }
+}
+
+impl<T> core::convert::AsRef<[T]> for FfiVec<T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> core::convert::AsMut<[T]> for FfiVec<T> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> core::ops::Deref for FfiVec<T> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T> core::ops::DerefMut for FfiVec<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+#[test]
+fn test_vec_push() {
+    let mut vec = FfiVec::from_vec(Vec::with_capacity(1));
+    assert!(vec.is_empty());
+    assert_eq!(vec.capacity(), 1);
+    vec.push(alloc::string::String::from("a"));
+    vec.push("b".into());
+    vec.push("c".into());
+    assert_eq!(vec.len(), 3);
+    assert!(vec.capacity() >= 3);
+    assert_eq!(vec.as_slice(), &["a", "b", "c"]);
+    assert_eq!(vec.into_vec(), alloc::vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_empty_vec() {
+    // ensure dropping empty vec is no-op
+    let mut empty = FfiVec::<u32>::new();
+    assert!(empty.as_slice().is_empty());
+    assert!(empty.as_mut_slice().is_empty());
+    drop(empty);
+}
+
+#[test]
+fn test_vec_extend() {
+    let mut results = FfiVec::new();
+    results.extend((1..=3).map(|i| i * 10));
+    results.extend(&[40, 50]);
+    assert!(results.capacity() >= 5);
+    assert_eq!(results.as_slice(), &[10, 20, 30, 40, 50]);
+
+    let boxed = crate::BoxedSlice::from(results);
+    assert_eq!(&*boxed, &[10, 20, 30, 40, 50]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vec_extend_panic() {
+    // a panicking iterator after reallocation must not leave a dangling buffer
+    let mut vec = FfiVec::from_vec(alloc::vec![alloc::string::String::from("a")]);
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        vec.extend((0..100).map(|i| {
+            assert!(i < 50);
+            alloc::format!("{}", i)
+        }));
+    }));
+    assert!(result.is_err());
+    drop(vec);
+}